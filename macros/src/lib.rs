@@ -1,15 +1,16 @@
 use proc_macro_crate::{crate_name, FoundCrate};
 use quote::quote;
-use syn::{parse::Parse, parse_macro_input, Ident, Lit};
+use syn::{parse::Parse, parse_macro_input, Expr, Ident};
 
 struct Input {
-    value: Lit,
+    /// A literal (`3.3`), a negated literal (`-3.3`) or any expression (`(a + b)`)
+    value: Expr,
     suffix_unit: String,
 }
 
 impl Parse for Input {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let value = input.parse::<Lit>()?;
+        let value = input.parse::<Expr>()?;
         let suffix_unit = input.parse::<Ident>()?.to_string();
         Ok(Input { value, suffix_unit })
     }
@@ -26,14 +27,28 @@ pub fn u(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
             let value = input.value.clone();
             let unit_ident = syn::Ident::new(unit, proc_macro2::Span::call_site());
 
-            let code = if suffix_len != 0 {
-                let suffix = syn::Ident::new(&input.suffix_unit[..suffix_len], proc_macro2::Span::call_site());
-                quote! {
-                    #cpath::#unit_ident::new(#cpath::num!(#value #suffix))
+            let code = match &value {
+                // Literals go through `num!`, which also validates the suffix
+                Expr::Lit(_) if suffix_len != 0 => {
+                    let suffix = syn::Ident::new(&input.suffix_unit[..suffix_len], proc_macro2::Span::call_site());
+                    quote! {
+                        #cpath::#unit_ident::from_symbol_value(#cpath::num!(#value #suffix))
+                    }
                 }
-            } else {
-                quote! {
-                    #cpath::#unit_ident::new(#cpath::num!(#value))
+                Expr::Lit(_) => {
+                    quote! {
+                        #cpath::#unit_ident::from_symbol_value(#cpath::num!(#value))
+                    }
+                }
+                _ => {
+                    let suffix_name = &input.suffix_unit[..suffix_len];
+                    let Some((_, suffix)) = SUFFIX_MAP.iter().find(|(name, _)| *name == suffix_name) else {
+                        continue;
+                    };
+                    let suffix = syn::Ident::new(suffix, proc_macro2::Span::call_site());
+                    quote! {
+                        #cpath::#unit_ident::from_symbol_value(#cpath::Number::new((#value) as f64, #cpath::Suffix::#suffix))
+                    }
                 }
             };
 
@@ -47,6 +62,72 @@ pub fn u(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         .into()
 }
 
+/// Parses a complex literal such as `cpx!(1.5 + 2.5uj)`, `cpx!(3m - 4j)` or
+/// `cpx!("2.5uj")` at compile time into a `Complex::new(...)` expression.
+#[proc_macro]
+pub fn cpx(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = proc_macro2::TokenStream::from(input);
+
+    let text = match syn::parse2::<syn::LitStr>(input.clone()) {
+        Ok(lit) => lit.value(),
+        Err(_) => input.to_string(),
+    };
+    let text: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+
+    let Some((re, im)) = parse_complex(&text) else {
+        return syn::Error::new_spanned(input, format!("Invalid complex literal `{}`", text))
+            .to_compile_error()
+            .into();
+    };
+
+    let cpath = get_crate_path();
+    let number = |(value, suffix): ParsedNumber| {
+        let suffix = syn::Ident::new(suffix, proc_macro2::Span::call_site());
+        let abs = proc_macro2::Literal::f64_unsuffixed(value.abs());
+        let value = if value.is_sign_negative() { quote! { -#abs } } else { quote! { #abs } };
+        quote! { #cpath::Number::new(#value, #cpath::Suffix::#suffix) }
+    };
+    let re = number(re);
+    let im = number(im);
+
+    quote! { #cpath::Complex::new(#re, #im) }.into()
+}
+
+/// A parsed value and the name of its `Suffix` variant
+type ParsedNumber = (f64, &'static str);
+
+/// Splits `a+bj`, `a-bj`, `bj` or `a` into its real and imaginary parts
+fn parse_complex(text: &str) -> Option<(ParsedNumber, ParsedNumber)> {
+    let zero = (0.0, "None");
+
+    let Some(body) = text.strip_suffix('j') else {
+        return Some((parse_number(text)?, zero));
+    };
+
+    // The split is the last sign that is neither leading nor part of an exponent
+    let split = body
+        .char_indices()
+        .skip(1)
+        .filter(|&(i, c)| (c == '+' || c == '-') && !body[..i].ends_with(['e', 'E']))
+        .map(|(i, _)| i)
+        .last();
+
+    match split {
+        Some(i) => Some((parse_number(&body[..i])?, parse_number(&body[i..])?)),
+        None => Some((zero, parse_number(body)?)),
+    }
+}
+
+fn parse_number(text: &str) -> Option<ParsedNumber> {
+    SUFFIX_MAP
+        .iter()
+        .filter_map(|(name, suffix)| {
+            let value = text.strip_suffix(name)?.parse::<f64>().ok()?;
+            value.is_finite().then_some((value, *suffix))
+        })
+        .next()
+}
+
 fn get_crate_path() -> proc_macro2::TokenStream {
     match crate_name("runit") {
         Ok(FoundCrate::Itself) => {
@@ -87,4 +168,23 @@ const UNITS_MAP: &[(&str, &str)] = &[
     ("m/s²", "Accel"),
     ("K", "Temperature"),
     ("rad", "Angle"),
+    ("g", "Mass"),
+];
+
+// "T" is both the tera prefix and the tesla unit: `u!(1.5 T)` is 1.5 tesla,
+// and a tera-scaled quantity needs the unit spelled out, e.g. `u!(1.5 TW)`.
+const SUFFIX_MAP: &[(&str, &str)] = &[
+    ("P", "Peta"),
+    ("T", "Tera"),
+    ("G", "Giga"),
+    ("M", "Mega"),
+    ("K", "Kilo"),
+    ("k", "Kilo"),
+    ("", "None"),
+    ("m", "Milli"),
+    ("u", "Micro"),
+    ("n", "Nano"),
+    ("p", "Pico"),
+    ("f", "Femto"),
+    ("a", "Atto"),
 ];
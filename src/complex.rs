@@ -1,11 +1,15 @@
 use core::fmt;
-use std::{ops::{Add, Div, Mul, Sub}, str::FromStr};
+use core::{ops::{Add, Div, Mul, Neg, Sub}, str::FromStr};
+use alloc::{boxed::Box, format, string::{String, ToString}};
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::Number;
+use crate::{Number, ParseError};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Equality is derived per part, and `Number` compares base values, so the
+/// suffix doesn't matter: `1k+2j` equals `1000+2j`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(not(feature = "approx-eq"), derive(Eq))]
 pub struct Complex {
     pub re: Number,
     pub im: Number,
@@ -20,6 +24,11 @@ impl Complex {
         (self.re, self.im)
     }
 
+    /// Real and imaginary parts as base-unit floats, e.g. for FFI or plotting
+    pub fn to_f64_parts(&self) -> (f64, f64) {
+        (self.re.to_f64(), self.im.to_f64())
+    }
+
     pub fn conjugate(self) -> Self {
         Self {
             re: self.re,
@@ -30,6 +39,71 @@ impl Complex {
     pub fn norm_sqr(self) -> Number {
         self.re * self.re + self.im * self.im
     }
+
+    /// Magnitude `|z|`
+    #[cfg(feature = "std")]
+    pub fn norm(self) -> Number {
+        Number::from_f64(self.norm_sqr().to_f64().sqrt())
+    }
+
+    /// Dot product treating both as 2D vectors: `re·re' + im·im'`
+    pub fn dot(self, other: Self) -> Number {
+        self.re * other.re + self.im * other.im
+    }
+
+    /// 2D cross product `re·im' - im·re'`, positive when `other` is counter-clockwise of `self`
+    pub fn perp_dot(self, other: Self) -> Number {
+        self.re * other.im - self.im * other.re
+    }
+
+    /// Multiply both parts by `factor`.
+    pub fn scale(self, factor: f64) -> Self {
+        Complex::new(self.re * factor, self.im * factor)
+    }
+
+    /// The unit-magnitude phasor with the same angle, or `None` for zero.
+    #[cfg(feature = "std")]
+    pub fn normalize(self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        Some(self.scale(self.norm().to_f64().recip()))
+    }
+
+    /// Round `re` and `im` separately to `sig` significant figures. See `Number::round_sig`.
+    #[cfg(feature = "std")]
+    pub fn round_components_sig(&self, sig: usize) -> Complex {
+        Complex::new(self.re.round_sig(sig), self.im.round_sig(sig))
+    }
+
+    /// `1 / z`. Panics on zero, like `Div`.
+    pub fn recip(self) -> Self {
+        Complex::new(1, 0) / self
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+/// Total impedance of `z` connected in series: `Z₁ + Z₂ + ...`
+pub fn series_impedance(z: &[Complex]) -> Complex {
+    z.iter().fold(Complex::new(0, 0), |acc, &zi| acc + zi)
+}
+
+/// Total impedance of `z` connected in parallel: `1 / (1/Z₁ + 1/Z₂ + ...)`.
+/// A zero impedance shorts the whole network, so the result is zero. An empty
+/// slice, or admittances that cancel (e.g. `[j, -j]`, an ideal LC tank at
+/// resonance), is an open circuit: `inf + 0j`, like `Resistance::parallel_all(&[])`.
+pub fn parallel_impedance(z: &[Complex]) -> Complex {
+    if z.iter().any(Complex::is_zero) {
+        return Complex::new(0, 0);
+    }
+    let admittance = z.iter().fold(Complex::new(0, 0), |acc, zi| acc + zi.recip());
+    if admittance.is_zero() {
+        return Complex::new(f64::INFINITY, 0);
+    }
+    admittance.recip()
 }
 
 impl Add for Complex {
@@ -50,6 +124,13 @@ impl Sub for Complex {
     }
 }
 
+impl Neg for Complex {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
 impl Mul for Complex {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self {
@@ -123,46 +204,47 @@ impl fmt::Display for Complex {
     }
 }
 
+impl From<(f64, f64)> for Complex {
+    fn from((re, im): (f64, f64)) -> Self {
+        Complex::new(re, im)
+    }
+}
+
 impl FromStr for Complex {
-    type Err = String;
+    type Err = ParseError;
 
+    /// Parses `a+bj`, `bj` or `a`. The imaginary marker may be `j` or `i`
+    /// (`"1+2i"`); it's only recognised as the last character, so `inf` is safe.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         fn find_real_imag_separator(s: &str) -> Option<usize> {
-            let mut chars = s.char_indices().peekable();
-            chars.next(); 
-
-            while let Some((i, c)) = chars.next() {
-                if (c == '+' || c == '-') && s[i+1..].contains('j') {
-                    return Some(i);
-                }
-            }
-            None
+            s.char_indices()
+                .skip(1)
+                .find(|&(i, c)| (c == '+' || c == '-') && !s[..i].ends_with(['e', 'E']))
+                .map(|(i, _)| i)
         }
         
         let s = s.trim();
+
+        let Some(imag) = s.strip_suffix(['j', 'i']) else {
+            let re = s.parse::<Number>()
+                .map_err(|e| ParseError::MalformedComplex { part: "real number", source: Box::new(e) })?;
+            return Ok(Complex { re, im: Number::zero() });
+        };
         
         // '+'  '-' 
-        if let Some(idx) = find_real_imag_separator(s) {
-            let (real_part, imag_part) = s.split_at(idx);
+        if let Some(idx) = find_real_imag_separator(imag) {
+            let (real_part, imag_part) = imag.split_at(idx);
             let real = real_part.trim().parse::<Number>()
-                .map_err(|e| format!("Parse real part error: {}", e))?;
-            let imag_str = imag_part.trim_end_matches('j');
-            let imag = imag_str.parse::<Number>()
-                .map_err(|e| format!("Parse imaginary part error: {}", e))?;
+                .map_err(|e| ParseError::MalformedComplex { part: "real part", source: Box::new(e) })?;
+            let imag = imag_part.parse::<Number>()
+                .map_err(|e| ParseError::MalformedComplex { part: "imaginary part", source: Box::new(e) })?;
             return Ok(Complex { re: real, im: imag });
         }
         
         // 1.2j、3uj
-        if s.ends_with('j') {
-            let imag_part = &s[..s.len()-1];
-            let im = imag_part.parse::<Number>()
-                .map_err(|e| format!("Parse imaginary part error: {}", e))?;
-            return Ok(Complex { re: Number::zero(), im });
-        }
-
-        let re = s.parse::<Number>()
-            .map_err(|e| format!("Parse real number error: {}", e))?;
-        Ok(Complex { re, im: Number::zero() })
+        let im = imag.parse::<Number>()
+            .map_err(|e| ParseError::MalformedComplex { part: "imaginary part", source: Box::new(e) })?;
+        Ok(Complex { re: Number::zero(), im })
     }
 }
 
@@ -188,7 +270,7 @@ impl<'de> Deserialize<'de> for Complex {
 
 #[cfg(test)]
 mod tests {
-    use crate::{complex, num, Suffix};
+    use crate::{complex, cpx, num, Suffix};
 
     use super::*;
 
@@ -233,6 +315,19 @@ mod tests {
         assert_eq!(c.im, Number::new(-7.5, Suffix::Nano));
     }
 
+    #[test]
+    fn test_i_marker() {
+        assert_eq!(Complex::from_str("3.3i").unwrap(), Complex::from_str("3.3j").unwrap());
+        assert_eq!(Complex::from_str("1+2i").unwrap(), Complex::from_str("1+2j").unwrap());
+        assert_eq!(Complex::from_str("1.1-2.2ui").unwrap(), complex!(1.1, -2.2 u));
+        assert_eq!(Complex::from_str("1e-3+2i").unwrap(), complex!(1 m, 2));
+        assert!(matches!(
+            Complex::from_str("inf").unwrap_err(),
+            ParseError::MalformedComplex { source, .. } if matches!(*source, ParseError::NonFinite(_))
+        ));
+        assert_eq!(Complex::from_str("1+2i").unwrap().to_string(), "1+2j");
+    }
+
     #[test]
     fn test_error_cases() {
         assert!(Complex::from_str("hello").is_err());
@@ -241,6 +336,20 @@ mod tests {
         assert!(Complex::from_str("j3.3").is_err());
     }
 
+    #[test]
+    fn test_parse_error_variants() {
+        use core::error::Error;
+
+        let err = Complex::from_str("1.2+badj").unwrap_err();
+        let ParseError::MalformedComplex { part, source } = &err else {
+            panic!("unexpected error {:?}", err);
+        };
+        assert_eq!(*part, "imaginary part");
+        assert_eq!(**source, ParseError::InvalidNumber("+bad".to_string()));
+        assert!(err.source().is_some());
+        assert_eq!(err.to_string(), "Parse imaginary part error: Parse number '+bad' error");
+    }
+
     #[test]
     fn test_creation() {
         let c = Complex { re: num!(3.0), im: num!(4.0) };
@@ -249,6 +358,42 @@ mod tests {
         assert_eq!(c.im, num!(4.0));
     }
 
+    #[test]
+    fn test_macro_j_form() {
+        assert_eq!(complex!(3.0 + 4.0 j), complex!(3.0, 4.0));
+        assert_eq!(complex!(3.0 m + 4.0 u j), complex!(3.0 m, 4.0 u));
+        assert_eq!(complex!(3.0 k + 4.0 j), complex!(3.0 k, 4.0));
+        assert_eq!(complex!(3.0 + 4.0 n j), complex!(3.0, 4.0 n));
+        assert_eq!(complex!(4.0 j), complex!(0.0, 4.0));
+        assert_eq!(complex!(4.0 m j), complex!(0.0, 4.0 m));
+        assert_eq!(complex!(3.0 - 4.0 j), complex!(3.0, -4.0));
+        assert_eq!(complex!(3.0 m - 4.0 u j), complex!(3.0 m, -4.0 u));
+        assert_eq!(complex!(3.0 k - 4.0 j), complex!(3.0 k, -4.0));
+        assert_eq!(complex!(3.0 - 4.0 n j), complex!(3.0, -4.0 n));
+        assert_eq!(complex!(3.0 - 4.0 j), "3-4j".parse::<Complex>().unwrap());
+    }
+
+    #[test]
+    fn test_cpx_macro() {
+        assert_eq!(cpx!(1.5 + 2.5uj), complex!(1.5, 2.5 u));
+        assert_eq!(cpx!(3m - 4j), complex!(3 m, -4));
+        assert_eq!(cpx!(2.5kj), complex!(0, 2.5 k));
+        assert_eq!(cpx!(-7n), complex!(-7 n, 0));
+        assert_eq!(cpx!(1e-3 + 2e-3j), complex!(1 m, 2 m));
+        assert_eq!(cpx!("1.5 + 2.5uj"), cpx!(1.5 + 2.5uj));
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn test_round_components_sig() {
+        let c = Complex::new(3.14159, 2.71828).round_components_sig(3);
+        assert_eq!(c, Complex::new(3.14, 2.72));
+        assert_eq!(c.to_string(), "3.14+2.72j");
+
+        let c = Complex::new(num!(4.7321 k), -0.012345).round_components_sig(2);
+        assert_eq!(c, complex!(4.7 k, -12 m));
+    }
+
     #[test]
     fn test_equality() {
         let a = Complex { re: num!(1.0), im: num!(2.0) };
@@ -314,4 +459,76 @@ mod tests {
         let parsed: Complex = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed, c);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_serialize_deserialize_complex_array() {
+        use crate::complex_array;
+
+        let c = Complex::new(3.0, 4.0);
+        let mut json = Vec::new();
+        complex_array::serialize(&c, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        assert_eq!(String::from_utf8(json).unwrap(), "[3.0,4.0]");
+
+        let parsed = complex_array::deserialize(&mut serde_json::Deserializer::from_str("[3.0, 4.0]")).unwrap();
+        assert_eq!(parsed, Complex::new(3.0, 4.0));
+
+        assert!(complex_array::deserialize(&mut serde_json::Deserializer::from_str("[3.0]")).is_err());
+    }
+
+    #[test]
+    fn test_series_parallel_impedance() {
+        let z = Complex::new(num!(100), num!(50));
+        assert_eq!(series_impedance(&[z, z]), Complex::new(num!(200), num!(100)));
+        let p = parallel_impedance(&[z, z]);
+        assert!((p.re.to_f64() - 50.).abs() < 1e-9);
+        assert!((p.im.to_f64() - 25.).abs() < 1e-9);
+        assert_eq!(parallel_impedance(&[z, Complex::new(0, 0)]), Complex::new(0, 0));
+
+        let open = parallel_impedance(&[]);
+        assert_eq!(open.re.to_f64(), f64::INFINITY);
+        assert!(open.im.is_zero());
+        let tank = parallel_impedance(&[Complex::new(0, 1), Complex::new(0, -1)]);
+        assert_eq!(tank.re.to_f64(), f64::INFINITY);
+        assert!(tank.im.is_zero());
+    }
+
+    #[test]
+    fn test_scale_normalize() {
+        let z = Complex::new(3, 4);
+        assert_eq!(z.norm(), num!(5));
+        assert_eq!(z.scale(2.), Complex::new(6, 8));
+        assert_eq!(-z, Complex::new(-3, -4));
+
+        let unit = z.normalize().unwrap();
+        assert!((unit.re.to_f64() - 0.6).abs() < 1e-12);
+        assert!((unit.im.to_f64() - 0.8).abs() < 1e-12);
+        assert_eq!(Complex::new(0, 0).normalize(), None);
+    }
+
+    #[test]
+    fn test_dot_perp_dot() {
+        let x = Complex::new(2, 0);
+        let y = Complex::new(0, 3);
+        assert_eq!(x.dot(y), num!(0));
+        assert_eq!(x.perp_dot(y), num!(6));
+        assert_eq!(y.perp_dot(x), num!(-6));
+        assert_eq!(Complex::new(1, 2).dot(Complex::new(3, 4)), num!(11));
+        assert_eq!(x.perp_dot(x), num!(0));
+    }
+
+    #[test]
+    fn test_eq_ignores_suffix() {
+        let c = Complex::from_str("1k+2j").unwrap();
+        assert_eq!(c, Complex::new(num!(1000.0), num!(2.0)));
+        assert_eq!(complex!(2.5 m, 500 u), Complex::new(0.0025, 0.0005));
+        assert_ne!(c, Complex::new(num!(1.0), num!(2.0)));
+    }
+
+    #[test]
+    fn test_f64_parts_round_trip() {
+        let c = Complex::from((3.0, 4.0));
+        assert_eq!(c, Complex::new(3, 4));
+        assert_eq!(c.to_f64_parts(), (3.0, 4.0));
+        assert_eq!(cpx!(1.5k + 2mj).to_f64_parts(), (1500.0, 0.002));
+    }
+}
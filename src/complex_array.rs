@@ -0,0 +1,14 @@
+//! `[re, im]` representation of a `Complex` for `#[serde(with = "runit::complex_array")]`.
+//! Both parts are written as base-unit floats, so suffixes are not preserved.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::{Complex, Number};
+
+pub fn serialize<S: Serializer>(c: &Complex, serializer: S) -> Result<S::Ok, S::Error> {
+    [c.re.to_f64(), c.im.to_f64()].serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Complex, D::Error> {
+    let [re, im] = <[f64; 2]>::deserialize(deserializer)?;
+    Ok(Complex::new(Number::from_f64(re), Number::from_f64(im)))
+}
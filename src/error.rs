@@ -0,0 +1,54 @@
+use core::fmt;
+use alloc::{boxed::Box, string::String};
+use crate::unit::DynError;
+
+/// Error returned by the `FromStr` impls of `Number`, `Suffix`, `UnitNumber`, `DynQuantity` and `Complex`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// The numeric part is not a number, e.g. `"abc"`
+    InvalidNumber(String),
+    /// The trailing prefix is not a known `Suffix`, e.g. the `x` in `"3.3x"`
+    UnknownSuffix(String),
+    /// NaN or an infinity, which parsing rejects
+    NonFinite(String),
+    /// The string doesn't end with the expected unit symbol, e.g. `"3.3A"` for a `Voltage`
+    WrongUnit { expected: &'static str },
+    /// The string doesn't end with any known unit symbol, e.g. `"3.3x"` for a `DynQuantity`
+    UnknownUnit(String),
+    /// One part of a complex number failed to parse
+    MalformedComplex { part: &'static str, source: Box<ParseError> },
+    /// A key looked up by `UnitNumber::from_map_field` is missing
+    MissingField(String),
+    /// The value under a key looked up by `UnitNumber::from_map_field` failed to parse
+    InvalidField { key: String, source: Box<ParseError> },
+    /// Both sides of an `eval_expr` expression parsed, but their units don't combine
+    Dimension(DynError),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidNumber(s) => write!(f, "Parse number '{}' error", s),
+            ParseError::UnknownSuffix(s) => write!(f, "Unknown suffix '{}'", s),
+            ParseError::NonFinite(s) => write!(f, "Non-finite number '{}'", s),
+            ParseError::WrongUnit { expected } => write!(f, "Expect end with '{}'", expected),
+            ParseError::UnknownUnit(s) => write!(f, "No known unit in '{}'", s),
+            ParseError::MalformedComplex { part, source } => write!(f, "Parse {} error: {}", part, source),
+            ParseError::MissingField(key) => write!(f, "Missing key '{}'", key),
+            ParseError::InvalidField { key, source } => write!(f, "Invalid value for '{}': {}", key, source),
+            ParseError::Dimension(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseError::MalformedComplex { source, .. } | ParseError::InvalidField { source, .. } => {
+                Some(source.as_ref())
+            }
+            ParseError::Dimension(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! C-compatible view of quantities, enabled with the `ffi` feature.
+
+use crate::{Unit, UnitNumber};
+
+/// A quantity in units of its symbol (base SI units, except grams for `Mass`)
+/// with that symbol as a UTF-8 byte slice.
+/// `unit` points at the `'static` symbol of the unit type and is not
+/// NUL-terminated; read exactly `unit_len` bytes.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CQuantity {
+    pub value: f64,
+    pub unit: *const u8,
+    pub unit_len: usize,
+}
+
+impl<U: Unit> UnitNumber<U> {
+    pub fn to_c_quantity(&self) -> CQuantity {
+        let symbol = U::name();
+        CQuantity {
+            value: self.symbol_value().to_f64(),
+            unit: symbol.as_ptr(),
+            unit_len: symbol.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{u, Voltage};
+
+    #[test]
+    fn test_voltage_to_c_quantity() {
+        let v: Voltage = u!(3.3 kV);
+        let q = v.to_c_quantity();
+        assert_eq!(q.value, 3300.0);
+
+        let unit = unsafe { std::slice::from_raw_parts(q.unit, q.unit_len) };
+        assert_eq!(std::str::from_utf8(unit).unwrap(), "V");
+
+        let q = u!(1 kΩ).to_c_quantity();
+        let unit = unsafe { std::slice::from_raw_parts(q.unit, q.unit_len) };
+        assert_eq!(std::str::from_utf8(unit).unwrap(), "Ω");
+    }
+}
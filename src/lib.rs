@@ -1,9 +1,65 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod error;
 pub mod number;
 pub mod complex;
+pub mod complex_array;
 pub mod unit;
 pub mod macros;
+#[cfg(feature = "num-traits")]
+pub mod scalar;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "rand")]
+pub mod sample;
 
+pub use error::*;
 pub use number::*;
 pub use complex::*;
 pub use unit::*;
-pub use runit_macros::*;
\ No newline at end of file
+pub use runit_macros::*;
+#[doc(hidden)]
+pub use paste;
+#[cfg(feature = "num-traits")]
+pub use scalar::*;
+#[cfg(feature = "ffi")]
+pub use ffi::*;
+#[cfg(feature = "rand")]
+pub use sample::*;
+
+/// Commonly used types, for `use runit::prelude::*;`.
+///
+/// The constructor macros (`num!`, `u!`, `v!`, `complex!`, ...) are exported at the
+/// crate root and are not part of the prelude; import them from `runit` directly.
+pub mod prelude {
+    pub use crate::error::ParseError;
+    pub use crate::number::{Number, Suffix};
+    pub use crate::complex::Complex;
+    pub use crate::unit::*;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::{complex, num, u};
+
+    #[test]
+    fn test_prelude_glob_import() {
+        let n: Number = num!(3.3 k);
+        assert_eq!(n.suffix, Suffix::Kilo);
+
+        let c: Complex = complex!(3.0, 4.0);
+        assert_eq!(c.norm_sqr(), num!(25.0));
+
+        let v: Voltage = u!(5.0 V);
+        let r: Resistance = u!(10.0 Ω);
+        let i: Current = v / r;
+        assert_eq!(i.to_string(), "500mA");
+
+        let t = Time::new(num!(2.0 m));
+        let f: Frequency = t.to_frquency();
+        assert_eq!(f.to_string(), "500Hz");
+    }
+}
@@ -10,6 +10,12 @@ macro_rules! num {
     ($val:literal M) => {
         $crate::Number::new($val as f64, $crate::Suffix::Mega)
     };
+    ($val:literal P) => {
+        $crate::Number::new($val as f64, $crate::Suffix::Peta)
+    };
+    ($val:literal T) => {
+        $crate::Number::new($val as f64, $crate::Suffix::Tera)
+    };
     ($val:literal G) => {
         $crate::Number::new($val as f64, $crate::Suffix::Giga)
     };
@@ -25,6 +31,12 @@ macro_rules! num {
     ($val:literal p) => {
         $crate::Number::new($val as f64, $crate::Suffix::Pico)
     };
+    ($val:literal f) => {
+        $crate::Number::new($val as f64, $crate::Suffix::Femto)
+    };
+    ($val:literal a) => {
+        $crate::Number::new($val as f64, $crate::Suffix::Atto)
+    };
     ($val:literal) => {
         $crate::Number::new($val as f64, $crate::Suffix::None)
     };
@@ -47,6 +59,46 @@ macro_rules! complex {
     ($re:literal , $im:literal ) => {
         $crate::Complex::new($crate::num!($re), $crate::num!($im))
     };
+
+    ($re:literal + $im:literal j) => {
+        $crate::Complex::new($crate::num!($re), $crate::num!($im))
+    };
+
+    ($re:literal + $im:literal $im_suffix:ident j) => {
+        $crate::Complex::new($crate::num!($re), $crate::num!($im $im_suffix))
+    };
+
+    ($re:literal $re_suffix:ident + $im:literal j) => {
+        $crate::Complex::new($crate::num!($re $re_suffix), $crate::num!($im))
+    };
+
+    ($re:literal $re_suffix:ident + $im:literal $im_suffix:ident j) => {
+        $crate::Complex::new($crate::num!($re $re_suffix), $crate::num!($im $im_suffix))
+    };
+
+    ($re:literal - $im:literal j) => {
+        $crate::Complex::new($crate::num!($re), -$crate::num!($im))
+    };
+
+    ($re:literal - $im:literal $im_suffix:ident j) => {
+        $crate::Complex::new($crate::num!($re), -$crate::num!($im $im_suffix))
+    };
+
+    ($re:literal $re_suffix:ident - $im:literal j) => {
+        $crate::Complex::new($crate::num!($re $re_suffix), -$crate::num!($im))
+    };
+
+    ($re:literal $re_suffix:ident - $im:literal $im_suffix:ident j) => {
+        $crate::Complex::new($crate::num!($re $re_suffix), -$crate::num!($im $im_suffix))
+    };
+
+    ($im:literal j) => {
+        $crate::Complex::new($crate::num!(0), $crate::num!($im))
+    };
+
+    ($im:literal $im_suffix:ident j) => {
+        $crate::Complex::new($crate::num!(0), $crate::num!($im $im_suffix))
+    };
 }
 
 #[macro_export]
@@ -195,3 +247,19 @@ macro_rules! ang {
         $crate::Angle::new($crate::num!($($t)*))
     };
 }
+
+
+/// A `Mass` in grams like `u!(.. g)`, e.g. `mass!(500 m)` is 500mg and `mass!(2 K)` is 2kg
+#[macro_export]
+macro_rules! mass {
+    ($($t:tt)*) => {
+        $crate::Mass::from_symbol_value($crate::num!($($t)*))
+    };
+}
+
+#[macro_export]
+macro_rules! nd {
+    ($($t:tt)*) => {
+        $crate::NoiseDensity::new($crate::num!($($t)*))
+    };
+}
\ No newline at end of file
@@ -1,11 +1,18 @@
-use std::fmt;
-use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
-use std::str::FromStr;
+use core::fmt::{self, Write};
+use core::num::FpCategory;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use core::str::FromStr;
+use alloc::{format, string::{String, ToString}};
+use crate::ParseError;
 use serde::{Serialize, Serializer, Deserialize, Deserializer};
-use std::cmp::Ordering;
+use core::cmp::Ordering;
+#[cfg(not(feature = "approx-eq"))]
+use core::hash::{Hash, Hasher};
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 pub enum Suffix {
+    Peta,  // 1e15
+    Tera,  // 1e12
     Giga,  // 1e9
     Mega,  // 1e6
     Kilo,  // 1e3
@@ -14,9 +21,11 @@ pub enum Suffix {
     Micro, // 1e-6
     Nano,  // 1e-9
     Pico,  // 1e-12
+    Femto, // 1e-15
+    Atto,  // 1e-18
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy)]
 pub struct Number {
     pub value: f64,
     pub suffix: Suffix,
@@ -25,6 +34,8 @@ pub struct Number {
 impl Suffix {
     pub const fn factor(&self) -> f64 {
         match self {
+            Suffix::Peta => 1e15,
+            Suffix::Tera => 1e12,
             Suffix::Giga => 1e9,
             Suffix::Mega => 1e6,
             Suffix::Kilo => 1e3,
@@ -33,11 +44,15 @@ impl Suffix {
             Suffix::Micro => 1e-6,
             Suffix::Nano => 1e-9,
             Suffix::Pico => 1e-12,
+            Suffix::Femto => 1e-15,
+            Suffix::Atto => 1e-18,
         }
     }
 
     pub const fn name(&self) -> &'static str {
         match self {
+            Suffix::Peta => "P",
+            Suffix::Tera => "T",
             Suffix::Giga => "G",
             Suffix::Mega => "M",
             Suffix::Kilo => "K",
@@ -46,29 +61,50 @@ impl Suffix {
             Suffix::Micro => "u",
             Suffix::Nano => "n",
             Suffix::Pico => "p",
+            Suffix::Femto => "f",
+            Suffix::Atto => "a",
         }
     }
 }
 
+// Debug shows the symbol next to the variant, e.g. `Kilo("K")`
+impl fmt::Debug for Suffix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let variant = match self {
+            Suffix::Peta => "Peta",
+            Suffix::Tera => "Tera",
+            Suffix::Giga => "Giga",
+            Suffix::Mega => "Mega",
+            Suffix::Kilo => "Kilo",
+            Suffix::None => "None",
+            Suffix::Milli => "Milli",
+            Suffix::Micro => "Micro",
+            Suffix::Nano => "Nano",
+            Suffix::Pico => "Pico",
+            Suffix::Femto => "Femto",
+            Suffix::Atto => "Atto",
+        };
+        f.debug_tuple(variant).field(&self.name()).finish()
+    }
+}
+
 impl FromStr for Suffix {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "G" => Ok(Suffix::Giga),
-            "M" => Ok(Suffix::Mega),
-            "K" => Ok(Suffix::Kilo),
-            "" => Ok(Suffix::None),
-            "m" => Ok(Suffix::Milli),
-            "u" => Ok(Suffix::Micro),
-            "n" => Ok(Suffix::Nano),
-            "p" => Ok(Suffix::Pico),
-            _ => Err(())
+        if s.is_empty() {
+            return Ok(Suffix::None);
         }
+        PREFIX_TABLE.iter()
+            .find(|(_, name)| *name == s)
+            .map(|(suffix, _)| *suffix)
+            .ok_or_else(|| ParseError::UnknownSuffix(s.to_string()))
     }
 }
 
-const PREFIX_VALUE_TABLE: [(Suffix, f64); 8] = [
+const PREFIX_VALUE_TABLE: [(Suffix, f64); 12] = [
+    (Suffix::Peta, 1e15),
+    (Suffix::Tera, 1e12),
     (Suffix::Giga, 1e9),
     (Suffix::Mega, 1e6),
     (Suffix::Kilo, 1e3),
@@ -77,19 +113,59 @@ const PREFIX_VALUE_TABLE: [(Suffix, f64); 8] = [
     (Suffix::Micro, 1e-6),
     (Suffix::Nano, 1e-9),
     (Suffix::Pico, 1e-12),
+    (Suffix::Femto, 1e-15),
+    (Suffix::Atto, 1e-18),
 ];
 
-const PREFIX_TABLE: [(Suffix, &'static str); 8] = [
+// Micro also accepts the micro sign (U+00B5) and Greek mu (U+03BC)
+const PREFIX_TABLE: [(Suffix, &str); 14] = [
+    (Suffix::Peta, "P"),
+    (Suffix::Tera, "T"),
     (Suffix::Giga, "G"),
     (Suffix::Mega, "M"),
     (Suffix::Kilo, "K"),
     (Suffix::Kilo, "k"),
     (Suffix::Milli, "m"),
     (Suffix::Micro, "u"),
+    (Suffix::Micro, "\u{b5}"),
+    (Suffix::Micro, "\u{3bc}"),
     (Suffix::Nano, "n"),
     (Suffix::Pico, "p"),
+    (Suffix::Femto, "f"),
+    (Suffix::Atto, "a"),
+];
+
+const PREFIX_WORD_TABLE: [(Suffix, &str); 11] = [
+    (Suffix::Peta, "peta"),
+    (Suffix::Tera, "tera"),
+    (Suffix::Giga, "giga"),
+    (Suffix::Mega, "mega"),
+    (Suffix::Kilo, "kilo"),
+    (Suffix::Milli, "milli"),
+    (Suffix::Micro, "micro"),
+    (Suffix::Nano, "nano"),
+    (Suffix::Pico, "pico"),
+    (Suffix::Femto, "femto"),
+    (Suffix::Atto, "atto"),
+];
+
+const NON_FINITE_TABLE: [(&str, f64); 3] = [
+    ("NaN", f64::NAN),
+    ("inf", f64::INFINITY),
+    ("-inf", f64::NEG_INFINITY),
 ];
 
+impl Suffix {
+    /// Split a trailing suffix off `s`, preferring the longest known suffix.
+    /// Returns the remaining text and `Suffix::None` if nothing matches.
+    pub fn parse_suffix(s: &str) -> (&str, Suffix) {
+        PREFIX_TABLE.iter()
+            .filter_map(|(suffix, name)| s.strip_suffix(name).map(|rest| (rest, *suffix)))
+            .min_by_key(|(rest, _)| rest.len())
+            .unwrap_or((s, Suffix::None))
+    }
+}
+
 impl Number {
     pub const fn new(value: f64, suffix: Suffix) -> Self {
         Number { value, suffix }
@@ -101,6 +177,10 @@ impl Number {
 
     pub fn from_f64<F: Into<f64>>(val: F) -> Self {
         let val = val.into();
+        if !val.is_finite() {
+            return Number::new(val, Suffix::None);
+        }
+
         let abs = val.abs();
         for (suffix, factor) in PREFIX_VALUE_TABLE.iter() {
             if abs >= *factor {
@@ -111,6 +191,17 @@ impl Number {
         Number::new(val, Suffix::None)
     }
 
+    /// Like `from_f64`, but only picks a prefix when `abs >= min_abs`, e.g. with
+    /// `min_abs = 1.0`, `0.5` stays `"0.5"` instead of `"500m"`, and with
+    /// `min_abs = 1e4`, `5000` stays `"5000"` instead of `"5K"`.
+    pub fn from_f64_threshold<F: Into<f64>>(val: F, min_abs: f64) -> Self {
+        let val = val.into();
+        if val.abs() < min_abs {
+            return Number::new(val, Suffix::None);
+        }
+        Number::from_f64(val)
+    }
+
     pub fn zero() -> Self {
         Self::new(0.0, Suffix::None)
     }
@@ -127,13 +218,172 @@ impl Number {
         self.value.is_finite()
     }
 
+    pub fn is_infinite(self) -> bool {
+        self.to_f64().is_infinite()
+    }
+
+    pub fn is_normal(self) -> bool {
+        self.to_f64().is_normal()
+    }
+
+    pub fn classify(self) -> FpCategory {
+        self.to_f64().classify()
+    }
+
+    /// -1, 0 or 1 by sign. Unlike `f64::signum`, zero (of either sign) gives 0;
+    /// NaN gives NaN.
+    pub fn signum(self) -> Self {
+        if self.value == 0. {
+            Self::zero()
+        } else {
+            Self::new(self.value.signum(), Suffix::None)
+        }
+    }
+
+    pub fn is_sign_positive(self) -> bool {
+        self.value.is_sign_positive()
+    }
+
+    pub fn is_sign_negative(self) -> bool {
+        self.value.is_sign_negative()
+    }
+
+    #[cfg(feature = "std")]
     pub fn powf(self, exp: f64) -> Self {
         Self::new(self.value.powf(exp), self.suffix)
     }
 
+    #[cfg(feature = "std")]
     pub fn atan2(self, other: f64) -> Self {
         Self::new(self.value.atan2(other), self.suffix)
     }
+
+    /// Fused multiply-add on base values: `self * a + b` with a single rounding.
+    #[cfg(feature = "std")]
+    pub fn mul_add(self, a: Number, b: Number) -> Number {
+        Number::from_f64(self.to_f64().mul_add(a.to_f64(), b.to_f64()))
+    }
+
+    /// Linear interpolation `self + (other - self) × t`; `t` outside `[0, 1]` extrapolates.
+    pub fn lerp(self, other: Number, t: f64) -> Number {
+        let a = self.to_f64();
+        Number::from_f64(a + (other.to_f64() - a) * t)
+    }
+
+    /// Round the base value to `places` decimal places; negative `places`
+    /// rounds to tens, hundreds, ... e.g. `12345.round_dp(-2) == 12300`.
+    #[cfg(feature = "std")]
+    pub fn round_dp(&self, places: i32) -> Number {
+        let val = self.to_f64();
+        let rounded = if places >= 0 {
+            let factor = 10f64.powi(places);
+            (val * factor).round() / factor
+        } else {
+            let factor = 10f64.powi(-places);
+            (val / factor).round() * factor
+        };
+        Number::from_f64(rounded)
+    }
+
+    /// Round the base value to `sig` significant figures (at least one),
+    /// e.g. `3.14159` to 3 gives `3.14`. Zero and non-finite values are unchanged.
+    #[cfg(feature = "std")]
+    pub fn round_sig(&self, sig: usize) -> Number {
+        let val = self.to_f64();
+        if val == 0. || !val.is_finite() {
+            return *self;
+        }
+        let (_, exp) = self.frexp10();
+        self.round_dp(sig.max(1) as i32 - 1 - exp)
+    }
+
+    fn finite_or_none(val: f64) -> Option<Number> {
+        val.is_finite().then(|| Number::from_f64(val))
+    }
+
+    /// Add, returning `None` if the sum is not finite.
+    pub fn checked_add(self, rhs: Number) -> Option<Number> {
+        Self::finite_or_none(self.to_f64() + rhs.to_f64())
+    }
+
+    /// Subtract, returning `None` if the difference is not finite.
+    pub fn checked_sub(self, rhs: Number) -> Option<Number> {
+        Self::finite_or_none(self.to_f64() - rhs.to_f64())
+    }
+
+    /// Multiply, returning `None` if the product is not finite.
+    pub fn checked_mul(self, rhs: Number) -> Option<Number> {
+        Self::finite_or_none(self.to_f64() * rhs.to_f64())
+    }
+
+    /// Divide, returning `None` if the quotient is not finite (e.g. division by zero).
+    pub fn checked_div(self, rhs: Number) -> Option<Number> {
+        Self::finite_or_none(self.to_f64() / rhs.to_f64())
+    }
+
+    /// Multiply, clamping an overflowing product to `±f64::MAX` instead of infinity.
+    /// A NaN product is returned unchanged.
+    pub fn saturating_mul(self, rhs: Number) -> Number {
+        let product = self.to_f64() * rhs.to_f64();
+        if product.is_infinite() {
+            Number::from_f64(f64::MAX.copysign(product))
+        } else {
+            Number::from_f64(product)
+        }
+    }
+
+    /// Split the base value into `(mantissa, exponent)` with `|mantissa|` in `[1, 10)`,
+    /// so `value == mantissa × 10^exponent`. Zero and non-finite values give `(value, 0)`.
+    #[cfg(feature = "std")]
+    pub fn frexp10(self) -> (f64, i32) {
+        let val = self.to_f64();
+        if val == 0. || !val.is_finite() {
+            return (val, 0);
+        }
+
+        let scale = |exp: i32| if exp >= 0 { val / 10f64.powi(exp) } else { val * 10f64.powi(-exp) };
+        let mut exp = val.abs().log10().floor() as i32;
+        let mut mantissa = scale(exp);
+        // log10 can land one off right at powers of ten
+        if mantissa.abs() >= 10. {
+            exp += 1;
+            mantissa = scale(exp);
+        } else if mantissa.abs() < 1. {
+            exp -= 1;
+            mantissa = scale(exp);
+        }
+        (mantissa, exp)
+    }
+
+    /// Pick a "nice" axis tick step (1, 2 or 5 × 10^k) close to `range / target_ticks`.
+    /// Returns zero when the range is zero/non-finite or `target_ticks` is 0.
+    #[cfg(feature = "std")]
+    pub fn nice_step(range: Number, target_ticks: usize) -> Number {
+        let raw = range.to_f64().abs() / target_ticks as f64;
+        if raw == 0. || !raw.is_finite() {
+            return Number::zero();
+        }
+
+        let magnitude = 10f64.powi(raw.log10().floor() as i32);
+        let fraction = raw / magnitude;
+        let nice = if fraction < 1.5 {
+            1.
+        } else if fraction < 3. {
+            2.
+        } else if fraction < 7. {
+            5.
+        } else {
+            10.
+        };
+
+        Number::from_f64(nice * magnitude)
+    }
+}
+
+impl Default for Number {
+    fn default() -> Self {
+        Number::zero()
+    }
 }
 
 macro_rules! impl_f64_like_method {
@@ -147,6 +397,14 @@ macro_rules! impl_f64_like_method {
 
 impl Number {
     impl_f64_like_method!(abs);
+    impl_f64_like_method!(recip);
+    impl_f64_like_method!(to_degrees);
+    impl_f64_like_method!(to_radians);
+}
+
+// These need the float math routines that only `std` provides
+#[cfg(feature = "std")]
+impl Number {
     impl_f64_like_method!(ceil);
     impl_f64_like_method!(floor);
     impl_f64_like_method!(round);
@@ -158,8 +416,8 @@ impl Number {
     impl_f64_like_method!(ln);
     impl_f64_like_method!(log10);
     impl_f64_like_method!(log2);
-    impl_f64_like_method!(recip);
-    
+
+
     impl_f64_like_method!(sin);
     impl_f64_like_method!(cos);
     impl_f64_like_method!(tan);
@@ -170,36 +428,156 @@ impl Number {
     impl_f64_like_method!(sinh);
     impl_f64_like_method!(cosh);
     impl_f64_like_method!(tanh);
-
-    impl_f64_like_method!(to_degrees);
-    impl_f64_like_method!(to_radians);
 }
 
 impl fmt::Display for Number {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(p) = f.precision() {
-            write!(f, "{:.*}{}", p, self.value, self.suffix.name())
+        // Non-finite values print as the canonical "NaN"/"inf"/"-inf", without a suffix
+        if !self.value.is_finite() {
+            return pad_formatted(f, &format!("{}", self.value));
+        }
+
+        // `{:#}` re-picks the suffix from the base value, e.g. 1000000 → 1M
+        let n = if f.alternate() { Number::from_f64(self.to_f64()) } else { *self };
+        let s = if let Some(p) = f.precision() {
+            format!("{:.*}{}", p, n.value, n.suffix.name())
         } else {
-            write!(f, "{}{}", self.value, self.suffix.name())
+            format!("{}{}", n.value, n.suffix.name())
+        };
+        pad_formatted(f, &s)
+    }
+}
+
+// Scientific notation of the base value, e.g. `1.5e3`; the suffix is folded into the exponent.
+// The alternate flag forces a sign on the exponent, e.g. `{:#e}` gives `1.5e+3`.
+macro_rules! impl_exp_fmt {
+    ($trait:ident, $lit:literal, $lit_p:literal) => {
+        impl fmt::$trait for Number {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let s = match f.precision() {
+                    Some(p) => format!($lit_p, p, self.to_f64()),
+                    None => format!($lit, self.to_f64()),
+                };
+                if f.alternate() {
+                    pad_formatted(f, &with_exp_sign(&s))
+                } else {
+                    pad_formatted(f, &s)
+                }
+            }
         }
+    };
+}
+
+/// Insert `+` before a non-negative exponent: `1.5e3` → `1.5e+3`
+pub(crate) fn with_exp_sign(s: &str) -> String {
+    match s.find(['e', 'E']) {
+        Some(i) if !s[i + 1..].starts_with('-') => format!("{}+{}", &s[..=i], &s[i + 1..]),
+        _ => s.to_string(),
     }
 }
 
+impl_exp_fmt!(LowerExp, "{:e}", "{:.*e}");
+impl_exp_fmt!(UpperExp, "{:E}", "{:.*E}");
+
+/// Write an already formatted value honouring width, fill, alignment and `0`.
+/// `Formatter::pad` can't be used since it would treat the precision as a
+/// truncation. Like the std numeric impls this right-aligns by default and
+/// zero-pads after the sign.
+pub(crate) fn pad_formatted(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    let len = s.chars().count();
+    let padding = match f.width() {
+        Some(width) if width > len => width - len,
+        _ => return f.write_str(s),
+    };
+
+    if f.sign_aware_zero_pad() {
+        let (sign, digits) = s.split_at(if s.starts_with(['-', '+']) { 1 } else { 0 });
+        f.write_str(sign)?;
+        for _ in 0..padding {
+            f.write_char('0')?;
+        }
+        return f.write_str(digits);
+    }
+
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+// Parsing only accepts finite values. Deserialization goes through
+// `parse_allow_non_finite` instead, so the "NaN"/"inf"/"-inf" that `Display`
+// writes for non-finite numbers still round-trip through serde.
 impl FromStr for Number {
-    type Err = String;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = Self::parse_allow_non_finite(s)?;
+        if !number.is_finite() {
+            return Err(ParseError::NonFinite(s.trim().to_string()));
+        }
+        Ok(number)
+    }
+}
+
+impl Number {
+    /// Like `from_str`, but also accepts spelled-out prefixes in any case,
+    /// e.g. `"3.3kilo"` or `"2.2 Micro"`.
+    pub fn from_str_verbose(s: &str) -> Result<Number, ParseError> {
         let s = s.trim();
-        for (suffix, suffix_str) in PREFIX_TABLE.iter() {
-            if s.ends_with(suffix_str) {
-                let num_str = &s[..s.len() - suffix_str.len()];
+        for (suffix, word) in PREFIX_WORD_TABLE.iter() {
+            let Some(split) = s.len().checked_sub(word.len()) else { continue };
+            let (num_str, tail) = match (s.get(..split), s.get(split..)) {
+                (Some(num_str), Some(tail)) => (num_str, tail),
+                _ => continue,
+            };
+            if tail.eq_ignore_ascii_case(word) {
                 let val: f64 = num_str.trim().parse()
-                    .map_err(|e| format!("Parse number '{}' error for '{}'", num_str, e))?;
+                    .map_err(|_| ParseError::InvalidNumber(num_str.trim().to_string()))?;
+                if !val.is_finite() {
+                    return Err(ParseError::NonFinite(s.to_string()));
+                }
                 return Ok(Number::new(val, *suffix));
             }
         }
-        // As none suffix
-        let val: f64 = s.parse().map_err(|e| format!("Parse number '{}' error for '{}'", s, e))?;
-        return Ok(Number::new(val, Suffix::None));
+        s.parse()
+    }
+
+    pub(crate) fn parse_allow_non_finite(s: &str) -> Result<Number, ParseError> {
+        let s = s.trim();
+        if let Some((_, val)) = NON_FINITE_TABLE.iter().find(|(name, _)| *name == s) {
+            return Ok(Number::new(*val, Suffix::None));
+        }
+
+        let (num_str, suffix) = Suffix::parse_suffix(s);
+        match num_str.trim().parse() {
+            Ok(val) => Ok(Number::new(val, suffix)),
+            Err(_) => Err(Self::parse_error_for(s)),
+        }
+    }
+
+    /// A number followed by letters that aren't a suffix is an unknown suffix,
+    /// anything else is an invalid number.
+    fn parse_error_for(s: &str) -> ParseError {
+        let tail_len: usize = s.chars().rev()
+            .take_while(|c| c.is_alphabetic())
+            .map(char::len_utf8)
+            .sum();
+        let (num_str, tail) = s.split_at(s.len() - tail_len);
+        if !tail.is_empty() && num_str.trim().parse::<f64>().is_ok() {
+            ParseError::UnknownSuffix(tail.to_string())
+        } else {
+            ParseError::InvalidNumber(s.to_string())
+        }
     }
 }
 
@@ -327,6 +705,49 @@ impl Rem<Number> for f64 {
     }
 }
 
+// Reference operands and compound assignment, all forwarding to the by-value impls
+macro_rules! impl_ref_op {
+    ($op:ident, $method:ident, $assign_op:ident, $assign_method:ident) => {
+        impl $op<&Number> for Number {
+            type Output = Number;
+            fn $method(self, rhs: &Number) -> Number {
+                $op::$method(self, *rhs)
+            }
+        }
+
+        impl $op<Number> for &Number {
+            type Output = Number;
+            fn $method(self, rhs: Number) -> Number {
+                $op::$method(*self, rhs)
+            }
+        }
+
+        impl $op<&Number> for &Number {
+            type Output = Number;
+            fn $method(self, rhs: &Number) -> Number {
+                $op::$method(*self, *rhs)
+            }
+        }
+
+        impl $assign_op for Number {
+            fn $assign_method(&mut self, rhs: Number) {
+                *self = $op::$method(*self, rhs);
+            }
+        }
+
+        impl $assign_op<&Number> for Number {
+            fn $assign_method(&mut self, rhs: &Number) {
+                *self = $op::$method(*self, *rhs);
+            }
+        }
+    };
+}
+
+impl_ref_op!(Add, add, AddAssign, add_assign);
+impl_ref_op!(Sub, sub, SubAssign, sub_assign);
+impl_ref_op!(Mul, mul, MulAssign, mul_assign);
+impl_ref_op!(Div, div, DivAssign, div_assign);
+
 impl Neg for Number {
     type Output = Self;
     fn neg(self) -> Self::Output {
@@ -337,9 +758,34 @@ impl Neg for Number {
     }
 }
 
+/// Relative tolerance of `Number` equality when the `approx-eq` feature is enabled.
+#[cfg(feature = "approx-eq")]
+pub const APPROX_EQ_EPSILON: f64 = 1e-12;
+
+#[cfg(not(feature = "approx-eq"))]
+fn base_eq(lhs: f64, rhs: f64) -> bool {
+    lhs == rhs
+}
+
+// Approximate equality isn't transitive, so with `approx-eq` enabled
+// `Number` is only `PartialEq`/`PartialOrd`: `Eq`, `Ord` and `Hash` are disabled.
+#[cfg(feature = "approx-eq")]
+fn base_eq(lhs: f64, rhs: f64) -> bool {
+    lhs == rhs || (lhs - rhs).abs() <= APPROX_EQ_EPSILON * lhs.abs().max(rhs.abs())
+}
+
+// Equality compares base values, so `1K == 1000` regardless of suffix.
+// As with f64, NaN is not equal to anything (itself included), so `Eq` and
+// `Hash` don't hold for NaN values and they should not be used as map keys.
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        base_eq(self.to_f64(), other.to_f64())
+    }
+}
+
 impl PartialEq<f64> for Number {
     fn eq(&self, other: &f64) -> bool {
-        self.to_f64() == *other
+        base_eq(self.to_f64(), *other)
     }
 }
 
@@ -349,6 +795,7 @@ impl PartialEq<Number> for f64 {
     }
 }
 
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for Number {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.to_f64().partial_cmp(&other.to_f64())
@@ -367,21 +814,48 @@ impl PartialOrd<Number> for f64 {
     }
 }
 
+#[cfg(not(feature = "approx-eq"))]
 impl Eq for Number {}
 
+#[cfg(not(feature = "approx-eq"))]
+impl Hash for Number {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let val = self.to_f64();
+        // 0.0 and -0.0 are equal, so they must hash the same
+        let val = if val == 0. { 0. } else { val };
+        val.to_bits().hash(state);
+    }
+}
+
+#[cfg(not(feature = "approx-eq"))]
 impl Ord for Number {
     fn cmp(&self, other: &Self) -> Ordering {
         self.partial_cmp(other).unwrap()
     }
 }
 
+// Numbers serialize as their display string (`"1.5m"`) unless the
+// `serde-float` feature is on, in which case they are plain base-unit floats.
+// Human-readable formats like JSON have no inf/NaN, so those stay the
+// `"inf"`/`"-inf"`/`"NaN"` strings under the feature too.
+// Deserializing accepts both forms either way in self-describing formats;
+// compact ones like bincode can only hand back the form that was written.
 impl Serialize for Number {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let s = self.to_string();
-        serializer.serialize_str(&s)
+        #[cfg(feature = "serde-float")]
+        {
+            if !self.value.is_finite() && serializer.is_human_readable() {
+                return serializer.collect_str(self);
+            }
+            serializer.serialize_f64(self.to_f64())
+        }
+        #[cfg(not(feature = "serde-float"))]
+        {
+            serializer.collect_str(self)
+        }
     }
 }
 
@@ -390,8 +864,45 @@ impl<'de> Deserialize<'de> for Number {
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        Number::from_str(&s).map_err(serde::de::Error::custom)
+        if deserializer.is_human_readable() {
+            return deserializer.deserialize_any(NumberVisitor);
+        }
+
+        #[cfg(feature = "serde-float")]
+        {
+            deserializer.deserialize_f64(NumberVisitor)
+        }
+        #[cfg(not(feature = "serde-float"))]
+        {
+            deserializer.deserialize_str(NumberVisitor)
+        }
+    }
+}
+
+/// Accepts either a plain number or a suffixed string such as `"1.5m"`
+struct NumberVisitor;
+
+impl serde::de::Visitor<'_> for NumberVisitor {
+    type Value = Number;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number or a string like \"1.5m\"")
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<Number, E> {
+        Ok(Number::from_f64(v))
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Number, E> {
+        Ok(Number::from_f64(v as f64))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Number, E> {
+        Ok(Number::from_f64(v as f64))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Number, E> {
+        Number::parse_allow_non_finite(v).map_err(E::custom)
     }
 }
 
@@ -407,7 +918,46 @@ mod tests {
         assert_eq!(Suffix::Giga.factor(), 1e9);
         assert_eq!(Suffix::Micro.name(), "u");
         assert_eq!(Suffix::from_str("K"), Ok(Suffix::Kilo));
-        assert_eq!(Suffix::from_str("z"), Err(()));
+        assert_eq!(Suffix::from_str("k"), Ok(Suffix::Kilo));
+        assert_eq!(Suffix::from_str("z"), Err(ParseError::UnknownSuffix("z".to_string())));
+    }
+
+    #[test]
+    fn test_suffix_debug() {
+        let s = format!("{:?}", Suffix::Kilo);
+        assert!(s.contains("Kilo") && s.contains("\"K\""));
+        assert_eq!(s, "Kilo(\"K\")");
+        assert_eq!(format!("{:?}", Suffix::Micro), "Micro(\"u\")");
+        assert_eq!(Suffix::Kilo.name(), "K");
+    }
+
+    #[test]
+    fn test_extended_prefixes() {
+        assert_eq!(num!(1.5 T), Number::new(1.5, Suffix::Tera));
+        assert_eq!(num!(2.0 P).to_f64(), 2e15);
+        assert_eq!(num!(3.0 f).suffix, Suffix::Femto);
+        assert_eq!(num!(4.0 a).suffix, Suffix::Atto);
+
+        assert_eq!(Number::from_f64(2e-15).suffix, Suffix::Femto);
+        assert_eq!(Number::from_f64(5e12).suffix, Suffix::Tera);
+        assert_eq!(Number::from_str("4.7f").unwrap(), Number::new(4.7, Suffix::Femto));
+        assert_eq!(Number::parse_allow_non_finite("inf").unwrap(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_parse_suffix() {
+        assert_eq!(Suffix::parse_suffix("3.3k"), ("3.3", Suffix::Kilo));
+        assert_eq!(Suffix::parse_suffix("3.3K"), ("3.3", Suffix::Kilo));
+        assert_eq!(Suffix::parse_suffix("2.2u"), ("2.2", Suffix::Micro));
+        assert_eq!(Suffix::parse_suffix("100"), ("100", Suffix::None));
+        assert_eq!(Suffix::parse_suffix(""), ("", Suffix::None));
+    }
+
+    #[test]
+    fn test_default() {
+        let n = Number::default();
+        assert!(n.is_zero());
+        assert_eq!(n.suffix, Suffix::None);
     }
 
     #[test]
@@ -443,6 +993,21 @@ mod tests {
         assert!(Number::from_str("3.3X").is_err());
     }
 
+    #[test]
+    fn test_number_from_str_sign_and_spaces() {
+        let a = Number::from_str("+3.3K").unwrap();
+        assert_eq!(a.suffix, Suffix::Kilo);
+        assert_eq!(a.value, 3.3);
+
+        let b = Number::from_str("3.3 K").unwrap();
+        assert_eq!(b.suffix, Suffix::Kilo);
+        assert_eq!(b.value, 3.3);
+
+        let c = Number::from_str(" -2.2 u ").unwrap();
+        assert_eq!(c.suffix, Suffix::Micro);
+        assert_eq!(c.value, -2.2);
+    }
+
     #[test]
     fn test_display() {
         let a = Number::new(1.23456, Suffix::Milli);
@@ -471,6 +1036,120 @@ mod tests {
         assert_eq!(g, 7.3 % 2.0);
     }
 
+    #[test]
+    #[cfg(not(feature = "approx-eq"))]
+    fn test_eq_and_hash_on_base_value() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashMap;
+
+        fn hash_of(n: Number) -> u64 {
+            let mut h = DefaultHasher::new();
+            n.hash(&mut h);
+            h.finish()
+        }
+
+        assert_eq!(num!(1.0 k), num!(1000.0));
+        assert_eq!(hash_of(num!(1.0 k)), hash_of(num!(1000.0)));
+        assert_eq!(hash_of(num!(0.0)), hash_of(-num!(0.0)));
+        assert_ne!(num!(1.0 k), num!(1.0));
+
+        let mut offsets = HashMap::new();
+        offsets.insert(num!(2.0 m), 0.1);
+        assert_eq!(offsets.get(&Number::new(2000.0, Suffix::Micro)), Some(&0.1));
+    }
+
+    #[test]
+    fn test_mul_add() {
+        let x = num!(1.5 k);
+        let a = num!(2.0 m);
+        let b = num!(0.5);
+        let fused = x.mul_add(a, b);
+        assert!((fused.to_f64() - (x * a + b).to_f64()).abs() < 1e-12);
+        assert_eq!(fused.to_f64(), 1.5e3f64.mul_add(2.0e-3, 0.5));
+    }
+
+    #[test]
+    fn test_classify() {
+        let normal = num!(3.3 k);
+        assert!(normal.is_normal() && normal.is_finite());
+        assert!(!normal.is_nan() && !normal.is_infinite());
+        assert_eq!(normal.classify(), FpCategory::Normal);
+
+        let zero = Number::zero();
+        assert!(!zero.is_normal() && zero.is_finite());
+        assert_eq!(zero.classify(), FpCategory::Zero);
+
+        let inf = Number::from(f64::INFINITY);
+        assert!(inf.is_infinite() && !inf.is_finite() && !inf.is_normal());
+        assert_eq!(inf.classify(), FpCategory::Infinite);
+
+        let nan = Number::from(f64::NAN);
+        assert!(nan.is_nan() && !nan.is_finite() && !nan.is_infinite());
+        assert_eq!(nan.classify(), FpCategory::Nan);
+    }
+
+    #[test]
+    fn test_signum() {
+        assert_eq!((-num!(3.0 m)).signum(), -1.0);
+        assert_eq!(num!(2.2 k).signum(), 1.0);
+        assert_eq!(num!(0.0).signum(), 0.0);
+        assert_eq!((-num!(0.0)).signum(), 0.0);
+        assert!(Number::from(f64::NAN).signum().is_nan());
+
+        assert!(num!(1.0 u).is_sign_positive());
+        assert!((-num!(1.0 u)).is_sign_negative());
+        assert!(!(-num!(1.0 u)).is_sign_positive());
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn test_round_dp() {
+        assert_eq!(num!(3.14159).round_dp(2), 3.14);
+        assert!((num!(12345.0).round_dp(-2).to_f64() - 12300.0).abs() < 1e-9);
+        assert!((num!(1.23456 m).round_dp(4).to_f64() - 0.0012).abs() < 1e-15);
+        assert_eq!(num!(2.5).round_dp(0), 3.0);
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        assert_eq!(num!(1.0).checked_div(num!(0.0)), None);
+        assert_eq!(num!(0.0).checked_div(num!(0.0)), None);
+        assert_eq!(num!(3 k).checked_div(num!(2)), Some(num!(1.5 k)));
+
+        assert_eq!(num!(1 m).checked_add(num!(2 m)), Some(num!(3 m)));
+        assert_eq!(Number::from_f64(f64::MAX).checked_add(Number::from_f64(f64::MAX)), None);
+        assert_eq!(num!(1 m).checked_sub(num!(2 m)), Some(num!(-1 m)));
+        assert_eq!(Number::from_f64(f64::INFINITY).checked_sub(num!(1)), None);
+    }
+
+    #[test]
+    fn test_overflow_checked_mul() {
+        let big = num!(1e200);
+        assert_eq!(big.checked_mul(big), None);
+        assert_eq!(num!(2.0 k).checked_mul(num!(3.0)), Some(num!(6.0 k)));
+
+        assert_eq!(big.saturating_mul(big), f64::MAX);
+        assert_eq!(big.saturating_mul(-big), -f64::MAX);
+        assert_eq!(num!(2.0).saturating_mul(num!(3.0)), num!(6.0));
+    }
+
+    #[test]
+    #[cfg(not(feature = "approx-eq"))]
+    fn test_exact_eq_by_default() {
+        assert_ne!(num!(0.1) + num!(0.2), num!(0.3));
+    }
+
+    #[test]
+    #[cfg(feature = "approx-eq")]
+    fn test_approx_eq() {
+        assert_eq!(num!(0.1) + num!(0.2), num!(0.3));
+        assert_eq!(num!(0.1) + num!(0.2), 0.3);
+        assert_eq!(num!(1.0 k) * num!(3.3 m), num!(3.3));
+        assert_ne!(num!(1.0), num!(1.001));
+        assert_ne!(Number::from(f64::NAN), Number::from(f64::NAN));
+        assert_eq!(Number::from(f64::INFINITY), Number::from(f64::INFINITY));
+    }
+
     #[test]
     fn test_number_f64_arithmetic() {
         let a = Number::new(3.3, Suffix::Kilo); // 3300
@@ -478,6 +1157,35 @@ mod tests {
         assert!((b.to_f64() - 3301.0).abs() < 1e-6);
     }
 
+    #[test]
+    fn test_num_macro_negative() {
+        let a = num!(-1.5 m);
+        assert_eq!(a.suffix, Suffix::Milli);
+        assert_eq!(a.value, -1.5);
+
+        let b = num!(-100);
+        assert_eq!(b.suffix, Suffix::None);
+        assert_eq!(b.value, -100.0);
+
+        let c = num!(-3.3 k);
+        assert_eq!(c.to_f64(), -3300.0);
+    }
+
+    #[test]
+    fn test_nice_step() {
+        let step = Number::nice_step(num!(1.0), 5);
+        assert!((step.to_f64() - 0.2).abs() < 1e-12);
+
+        let step = Number::nice_step(num!(4700.0), 5);
+        assert_eq!(step, 1000.0);
+
+        let step = Number::nice_step(num!(3.3 k), 10);
+        assert!((step.to_f64() - 500.0).abs() < 1e-9);
+
+        assert!(Number::nice_step(num!(1.0), 0).is_zero());
+        assert!(Number::nice_step(num!(0.0), 5).is_zero());
+    }
+
     #[test]
     fn test_num_macro() {
         use crate::num;
@@ -497,6 +1205,7 @@ mod tests {
     use serde_json;
 
     #[test]
+    #[cfg(not(feature = "serde-float"))]
     fn test_serialize_number() {
         let n = Number::new(1.5, Suffix::Milli);
         let json = serde_json::to_string(&n).unwrap();
@@ -518,6 +1227,34 @@ mod tests {
         assert_eq!(original, parsed);
     }
 
+    #[test]
+    #[cfg(not(feature = "serde-float"))]
+    fn test_non_finite_roundtrip() {
+        let inf = Number::from_f64(f64::INFINITY);
+        assert_eq!(inf.suffix, Suffix::None);
+        assert_eq!(inf.to_string(), "inf");
+        assert_eq!(Number::new(f64::NEG_INFINITY, Suffix::Kilo).to_string(), "-inf");
+        assert_eq!(Number::from_f64(f64::NAN).to_string(), "NaN");
+
+        let json = serde_json::to_string(&inf).unwrap();
+        assert_eq!(json, "\"inf\"");
+        let parsed: Number = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, inf);
+
+        let parsed: Number = serde_json::from_str("\"NaN\"").unwrap();
+        assert!(parsed.is_nan());
+    }
+
+    #[test]
+    fn test_from_str_rejects_non_finite() {
+        assert!(Number::from_str("inf").is_err());
+        assert!(Number::from_str("-inf").is_err());
+        assert!(Number::from_str("NaN").is_err());
+        assert!(Number::from_str("infinity").is_err());
+        assert!(Number::from_str("1e999k").is_err());
+        assert_eq!(Number::from_str(" -inf ").unwrap_err().to_string(), "Non-finite number '-inf'");
+    }
+
     #[test]
     fn test_deserialize_invalid_number() {
         let json = "\"bad_number\"";
@@ -531,4 +1268,201 @@ mod tests {
         let n: Number = serde_json::from_str(json).unwrap();
         assert_eq!(n, Number::new(42.0, Suffix::None));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_serde_compact_roundtrip() {
+        use serde_test::{Configure, Token, assert_tokens};
+
+        let n = Number::new(1.5, Suffix::Milli);
+        #[cfg(not(feature = "serde-float"))]
+        assert_tokens(&n.compact(), &[Token::Str("1.5m")]);
+        #[cfg(feature = "serde-float")]
+        assert_tokens(&n.compact(), &[Token::F64(1.5e-3)]);
+    }
+
+    #[test]
+    #[cfg(feature = "serde-float")]
+    fn test_serde_float() {
+        let json = serde_json::to_string(&num!(1.5 k)).unwrap();
+        assert_eq!(json, "1500.0");
+
+        let parsed: Number = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, num!(1.5 k));
+
+        let parsed: Number = serde_json::from_str("\"2.2u\"").unwrap();
+        assert_eq!(parsed, num!(2.2 u));
+        let parsed: Number = serde_json::from_str("42").unwrap();
+        assert_eq!(parsed, num!(42));
+    }
+
+    #[test]
+    #[cfg(feature = "serde-float")]
+    fn test_serde_float_non_finite() {
+        use serde_test::{Configure, Token, assert_tokens};
+
+        let inf = Number::from_f64(f64::NEG_INFINITY);
+        let json = serde_json::to_string(&inf).unwrap();
+        assert_eq!(json, "\"-inf\"");
+        assert_eq!(serde_json::from_str::<Number>(&json).unwrap(), inf);
+
+        let json = serde_json::to_string(&Number::from_f64(f64::NAN)).unwrap();
+        assert_eq!(json, "\"NaN\"");
+        assert!(serde_json::from_str::<Number>(&json).unwrap().is_nan());
+
+        // Compact formats carry the float as is
+        assert_tokens(&inf.compact(), &[Token::F64(f64::NEG_INFINITY)]);
+    }
+
+    #[test]
+    fn test_reference_operands() {
+        let a = num!(1.5 k);
+        let b = num!(500);
+        let (ra, rb) = (&a, &b);
+
+        assert_eq!(a + rb, a + b);
+        assert_eq!(ra - b, a - b);
+        assert_eq!(ra * rb, a * b);
+        assert_eq!(a / rb, a / b);
+
+        let mut n = a;
+        n += rb;
+        assert_eq!(n, num!(2 k));
+        n -= b;
+        assert_eq!(n, a);
+        n *= &num!(2);
+        assert_eq!(n, num!(3 k));
+        n /= num!(3);
+        assert_eq!(n, num!(1 k));
+    }
+
+    #[test]
+    fn test_deserialize_json_number() {
+        let n: Number = serde_json::from_str("42.0").unwrap();
+        let s: Number = serde_json::from_str("\"42.0\"").unwrap();
+        assert_eq!(n, s);
+        assert_eq!(n, Number::new(42.0, Suffix::None));
+
+        let n: Number = serde_json::from_str("-3").unwrap();
+        assert_eq!(n, num!(-3));
+        assert!(serde_json::from_str::<Number>("true").is_err());
+    }
+
+    #[test]
+    fn test_frexp10() {
+        assert_eq!(num!(3.3 k).frexp10(), (3.3, 3));
+        assert_eq!(num!(1000).frexp10(), (1.0, 3));
+        assert_eq!(num!(-2.5).frexp10(), (-2.5, 0));
+        assert_eq!(num!(1 m).frexp10(), (1.0, -3));
+        assert_eq!(num!(0).frexp10(), (0.0, 0));
+
+        let (m, e) = num!(47 u).frexp10();
+        assert!((m - 4.7).abs() < 1e-12);
+        assert_eq!(e, -5);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let (a, b) = (num!(1 k), num!(3 k));
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 0.5), num!(2 k));
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 2.0), num!(5 k));
+        assert_eq!(a.lerp(b, -1.0), num!(-1 k));
+    }
+
+    #[test]
+    fn test_alternate_display_normalizes() {
+        let n = Number::new(1e6, Suffix::None);
+        assert_eq!(format!("{}", n), "1000000");
+        assert_eq!(format!("{:#}", n), "1M");
+
+        let n = Number::new(0.0047, Suffix::Kilo);
+        assert_eq!(format!("{}", n), "0.0047K");
+        assert_eq!(format!("{:#}", n), "4.7");
+        assert_eq!(format!("{:#.2}", Number::new(2200., Suffix::Milli)), "2.20");
+    }
+
+    #[test]
+    fn test_display_width_and_fill() {
+        assert_eq!(format!("{:>8}", num!(3.3 m)), "    3.3m");
+        assert_eq!(format!("{:8}", num!(3.3 m)), "    3.3m");
+        assert_eq!(format!("{:<8}|", num!(3.3 m)), "3.3m    |");
+        assert_eq!(format!("{:*^8}", num!(3.3 m)), "**3.3m**");
+        assert_eq!(format!("{:08.2}", num!(-3.3 m)), "-003.30m");
+        assert_eq!(format!("{:2}", num!(3.3 m)), "3.3m");
+    }
+
+    #[test]
+    fn test_exp_format() {
+        assert_eq!(format!("{:e}", num!(1.5 k)), "1.5e3");
+        assert_eq!(format!("{:E}", num!(2.2 u)), "2.2E-6");
+        assert_eq!(format!("{:.2e}", num!(3.3)), "3.30e0");
+        assert_eq!(format!("{:>8e}", num!(-1 m)), "   -1e-3");
+    }
+
+    #[test]
+    fn test_exp_format_forced_sign() {
+        assert_eq!(format!("{:#e}", num!(1 k)), "1e+3");
+        assert_eq!(format!("{:#E}", num!(2.2 u)), "2.2E-6");
+        assert_eq!(format!("{:#.1e}", num!(3.3)), "3.3e+0");
+        assert_eq!(format!("{:#e}", num!(-4.7 M)), "-4.7e+6");
+        assert_eq!(format!("{:>#8e}", num!(1 k)), "    1e+3");
+        assert_eq!(format!("{:>7e}", num!(1 k)), "    1e3");
+    }
+
+    #[test]
+    fn test_round_sig() {
+        assert_eq!(num!(1.23456).round_sig(3), num!(1.23));
+        assert_eq!(num!(4.7321 k).round_sig(2), num!(4.7 k));
+        assert_eq!(num!(-0.0012345).round_sig(2), num!(-1.2 m));
+        assert_eq!(num!(987).round_sig(1), num!(1 k));
+        assert_eq!(num!(987).round_sig(0), num!(1 k));
+        assert_eq!(num!(0).round_sig(3), num!(0));
+    }
+
+    #[test]
+    fn test_from_str_verbose() {
+        assert_eq!(Number::from_str_verbose("3.3kilo").unwrap(), Number::new(3.3, Suffix::Kilo));
+        assert_eq!(Number::from_str_verbose("2.2micro").unwrap(), Number::new(2.2, Suffix::Micro));
+        assert_eq!(Number::from_str_verbose("1.5 MEGA").unwrap(), Number::new(1.5, Suffix::Mega));
+        assert_eq!(Number::from_str_verbose("4.7Nano").unwrap(), Number::new(4.7, Suffix::Nano));
+
+        // Compact forms still work, and plain `from_str` doesn't take words
+        assert_eq!(Number::from_str_verbose("2.2m").unwrap(), Number::new(2.2, Suffix::Milli));
+        assert!(Number::from_str("3.3kilo").is_err());
+        assert!(Number::from_str_verbose("kilo").is_err());
+        assert!(Number::from_str_verbose("infkilo").is_err());
+    }
+
+    #[test]
+    fn test_unicode_micro() {
+        assert_eq!(Suffix::from_str("\u{b5}"), Ok(Suffix::Micro));
+        assert_eq!(Suffix::from_str("\u{3bc}"), Ok(Suffix::Micro));
+        assert_eq!(Number::from_str("2.2\u{b5}").unwrap(), Number::new(2.2, Suffix::Micro));
+        assert_eq!(Number::from_str("2.2\u{3bc}").unwrap(), Number::new(2.2, Suffix::Micro));
+        assert_eq!(Number::from_str("2.2u").unwrap(), Number::new(2.2, Suffix::Micro));
+        assert!(Number::from_str("\u{b5}").is_err());
+    }
+
+    #[test]
+    fn test_parse_error_variants() {
+        assert_eq!(Number::from_str("abc"), Err(ParseError::InvalidNumber("abc".to_string())));
+        assert_eq!(Number::from_str("3.3x"), Err(ParseError::UnknownSuffix("x".to_string())));
+        assert_eq!(Number::from_str("2kilo"), Err(ParseError::UnknownSuffix("kilo".to_string())));
+        assert_eq!(Number::from_str("NaN"), Err(ParseError::NonFinite("NaN".to_string())));
+        assert_eq!(Number::from_str("1.2.3m"), Err(ParseError::InvalidNumber("1.2.3m".to_string())));
+
+        assert_eq!(ParseError::UnknownSuffix("x".to_string()).to_string(), "Unknown suffix 'x'");
+    }
+
+    #[test]
+    fn test_from_f64_threshold() {
+        assert_eq!(Number::from_f64(0.5).to_string(), "500m");
+        assert_eq!(Number::from_f64_threshold(0.5, 1.0).to_string(), "0.5");
+        assert_eq!(Number::from_f64_threshold(-0.05, 1.0).to_string(), "-0.05");
+        assert_eq!(Number::from_f64_threshold(0.05, 0.01).to_string(), "50m");
+        assert_eq!(Number::from_f64_threshold(3300., 1.0).to_string(), "3.3K");
+        assert_eq!(Number::from_f64_threshold(5000., 1e4).to_string(), "5000");
+        assert_eq!(Number::from_f64_threshold(20000., 1e4).to_string(), "20K");
+    }
+}
@@ -0,0 +1,120 @@
+//! Random sampling of `Number`s and `UnitNumber`s, enabled with the `rand` feature,
+//! e.g. for Monte Carlo tolerance analysis.
+//!
+//! Both distributions sample `Number` as well as any `UnitNumber<U>`, so
+//! `rng.sample::<Resistance, _>(&dist)` picks a resistance directly.
+
+use rand::{distributions::{Distribution, Uniform}, Rng};
+#[cfg(feature = "std")]
+use rand_distr::{Normal, NormalError};
+
+use crate::{Number, Unit, UnitNumber};
+
+/// Uniform distribution over `[low, high]`
+#[derive(Debug, Clone, Copy)]
+pub struct UniformNumber {
+    inner: Uniform<f64>,
+}
+
+impl UniformNumber {
+    /// Panics if `low > high` or either bound is not finite.
+    pub fn new(low: Number, high: Number) -> Self {
+        Self { inner: Uniform::new_inclusive(low.to_f64(), high.to_f64()) }
+    }
+}
+
+impl Distribution<Number> for UniformNumber {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Number {
+        Number::from_f64(self.inner.sample(rng))
+    }
+}
+
+impl<U: Unit> Distribution<UnitNumber<U>> for UniformNumber {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UnitNumber<U> {
+        UnitNumber::new(Distribution::<Number>::sample(self, rng))
+    }
+}
+
+/// Normal (Gaussian) distribution with the given mean and standard deviation
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy)]
+pub struct NormalNumber {
+    inner: Normal<f64>,
+}
+
+#[cfg(feature = "std")]
+impl NormalNumber {
+    /// Errors if `std_dev` is not finite; a negative `std_dev` mirrors the distribution.
+    pub fn new(mean: Number, std_dev: Number) -> Result<Self, NormalError> {
+        Ok(Self { inner: Normal::new(mean.to_f64(), std_dev.to_f64())? })
+    }
+}
+
+#[cfg(feature = "std")]
+impl Distribution<Number> for NormalNumber {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Number {
+        Number::from_f64(self.inner.sample(rng))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<U: Unit> Distribution<UnitNumber<U>> for NormalNumber {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UnitNumber<U> {
+        UnitNumber::new(Distribution::<Number>::sample(self, rng))
+    }
+}
+
+impl<U: Unit> UnitNumber<U> {
+    /// Uniform distribution over `self ± tolerance`, with `tolerance` as a fraction
+    /// (`0.05` for ±5%).
+    pub fn tolerance_dist(self, tolerance: f64) -> UniformNumber {
+        let (a, b) = (self.to_f64() * (1. - tolerance), self.to_f64() * (1. + tolerance));
+        UniformNumber::new(Number::from_f64(a.min(b)), Number::from_f64(a.max(b)))
+    }
+
+    /// A random value within `self ± tolerance`, e.g. one resistor out of a ±5% batch.
+    pub fn sample_tolerance<R: Rng + ?Sized>(self, tolerance: f64, rng: &mut R) -> Self {
+        self.tolerance_dist(tolerance).sample(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::SmallRng, SeedableRng};
+
+    use crate::{num, u, Resistance};
+
+    use super::*;
+
+    #[test]
+    fn test_uniform_number_in_bounds() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let dist = UniformNumber::new(num!(1 m), num!(2 m));
+        for _ in 0..1000 {
+            let n: Number = rng.sample(dist);
+            assert!(n >= num!(1 m) && n <= num!(2 m));
+        }
+    }
+
+    #[test]
+    fn test_sample_tolerance() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let nominal = u!(10 kΩ);
+        for _ in 0..1000 {
+            let r = nominal.sample_tolerance(0.05, &mut rng);
+            assert!(r >= u!(9.5 kΩ) && r <= u!(10.5 kΩ));
+        }
+
+        let r: Resistance = rng.sample(u!(-1 Ω).tolerance_dist(0.1));
+        assert!(r >= u!(-1.1 Ω) && r <= u!(-0.9 Ω));
+    }
+
+    #[test]
+    fn test_normal_number() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let dist = NormalNumber::new(num!(5), num!(100 m)).unwrap();
+        let mean = (0..10_000).map(|_| Distribution::<Number>::sample(&dist, &mut rng).to_f64()).sum::<f64>() / 10_000.;
+        assert!((mean - 5.).abs() < 0.01);
+        assert!(NormalNumber::new(num!(5), Number::from_f64(f64::INFINITY)).is_err());
+    }
+}
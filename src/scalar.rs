@@ -0,0 +1,71 @@
+//! `num-traits` integration, enabled with the `num-traits` feature.
+//!
+//! `Number` implements `num_traits::Num`, so it can be used wherever generic
+//! numeric code expects a field-like scalar. `UnitNumber<U>` deliberately does
+//! not: `Num` requires `Mul<Self, Output = Self>`, but multiplying two
+//! quantities changes the dimension (V × V is not a voltage), so a unit type
+//! can only ever be a vector-space element scaled by a `Number`.
+
+use alloc::{format, string::{String, ToString}};
+use num_traits::{Num, One, Zero};
+
+use crate::{Number, Suffix};
+
+impl Zero for Number {
+    fn zero() -> Self {
+        Number::zero()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.to_f64() == 0.
+    }
+}
+
+impl One for Number {
+    fn one() -> Self {
+        Number::new(1.0, Suffix::None)
+    }
+}
+
+impl Num for Number {
+    type FromStrRadixErr = String;
+
+    /// Only radix 10 is supported, using the same syntax as `FromStr` (suffixes included).
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        if radix != 10 {
+            return Err(format!("Unsupported radix '{}' for Number", radix));
+        }
+        s.parse().map_err(|e: crate::ParseError| e.to_string())
+    }
+}
+
+/// Marker for types usable as elements in generic (nalgebra-style) numeric code.
+pub trait Scalar: Num + Copy + PartialOrd {}
+
+impl<T: Num + Copy + PartialOrd> Scalar for T {}
+
+#[cfg(test)]
+mod tests {
+    use crate::num;
+
+    use super::*;
+
+    fn dot<T: Scalar>(a: &[T], b: &[T]) -> T {
+        a.iter().zip(b).fold(T::zero(), |acc, (&x, &y)| acc + x * y)
+    }
+
+    #[test]
+    fn test_generic_dot_product() {
+        let a = [num!(1.0), num!(2.0 k), num!(3.0 m)];
+        let b = [num!(4.0), num!(5.0 m), num!(6.0)];
+        assert_eq!(dot(&a, &b), num!(4.0) + num!(10.0) + num!(18.0 m));
+    }
+
+    #[test]
+    fn test_zero_one_and_radix() {
+        assert!(<Number as Zero>::is_zero(&Number::zero()));
+        assert_eq!(Number::one() * num!(3.3 k), num!(3.3 k));
+        assert_eq!(Number::from_str_radix("2.2u", 10), Ok(num!(2.2 u)));
+        assert!(Number::from_str_radix("ff", 16).is_err());
+    }
+}
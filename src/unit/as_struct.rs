@@ -0,0 +1,61 @@
+//! Struct representation of a `UnitNumber` for `#[serde(with = "runit::unit::as_struct")]`:
+//! `3.3kV` becomes `{"value": 3.3, "suffix": "K", "unit": "V"}` instead of `"3.3KV"`.
+
+use core::{fmt, marker::PhantomData};
+use alloc::{format, string::String};
+use serde::{de, ser::SerializeStruct, Deserializer, Serializer};
+use crate::{Number, Suffix};
+use super::{Unit, UnitNumber};
+
+const FIELDS: &[&str] = &["value", "suffix", "unit"];
+
+pub fn serialize<U: Unit, S: Serializer>(v: &UnitNumber<U>, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct("UnitNumber", FIELDS.len())?;
+    let number = v.symbol_value();
+    state.serialize_field("value", &number.value)?;
+    state.serialize_field("suffix", number.suffix.name())?;
+    state.serialize_field("unit", U::name())?;
+    state.end()
+}
+
+pub fn deserialize<'de, U: Unit, D: Deserializer<'de>>(deserializer: D) -> Result<UnitNumber<U>, D::Error> {
+    deserializer.deserialize_struct("UnitNumber", FIELDS, StructVisitor(PhantomData))
+}
+
+struct StructVisitor<U>(PhantomData<U>);
+
+impl<'de, U: Unit> de::Visitor<'de> for StructVisitor<U> {
+    type Value = UnitNumber<U>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a struct with value, suffix and unit \"{}\"", U::name())
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut value: Option<f64> = None;
+        let mut suffix: Option<Suffix> = None;
+        let mut unit: Option<String> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "value" => value = Some(map.next_value()?),
+                "suffix" => {
+                    let s: String = map.next_value()?;
+                    let parsed = s.parse().map_err(|_| de::Error::custom(format!("Invalid suffix '{}'", s)))?;
+                    suffix = Some(parsed);
+                }
+                "unit" => unit = Some(map.next_value()?),
+                other => return Err(de::Error::unknown_field(other, FIELDS)),
+            }
+        }
+
+        let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+        let suffix = suffix.ok_or_else(|| de::Error::missing_field("suffix"))?;
+        let unit = unit.ok_or_else(|| de::Error::missing_field("unit"))?;
+        if unit != U::name() {
+            return Err(de::Error::custom(format!("Expect unit '{}', got '{}'", U::name(), unit)));
+        }
+
+        Ok(UnitNumber::from_symbol_value(Number::new(value, suffix)))
+    }
+}
@@ -0,0 +1,172 @@
+//! Quantities whose unit is only known at runtime, e.g. values read from data
+//! files. Arithmetic is checked when it runs instead of at compile time.
+
+use core::{fmt, str::FromStr};
+use alloc::string::ToString;
+use crate::{Number, ParseError};
+use super::*;
+
+macro_rules! dyn_units {
+    ($($name:ident),* $(,)?) => {
+        paste::paste! {
+            /// Runtime tag for each built-in unit
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum DynUnit {
+                $($name,)*
+            }
+
+            impl DynUnit {
+                pub const ALL: &'static [DynUnit] = &[$(DynUnit::$name,)*];
+
+                /// The unit symbol, the same as `Unit::name` of the static type.
+                pub fn name(self) -> &'static str {
+                    match self {
+                        $(DynUnit::$name => [<$name Unit>]::name(),)*
+                    }
+                }
+
+                /// See `Unit::symbol_factor`.
+                pub fn symbol_factor(self) -> f64 {
+                    match self {
+                        $(DynUnit::$name => [<$name Unit>]::symbol_factor(),)*
+                    }
+                }
+            }
+        }
+    };
+}
+
+dyn_units!(
+    Voltage, Current, Resistance, Capacitance, Inductance, Charge, Power, Energy,
+    Time, Frequency, Length, Area, Force, Pressure, MagneticFlux, FluxDensity,
+    Conductance, Velocity, Accel, Temperature, Angle, Mass, NoiseDensity,
+);
+
+impl fmt::Display for DynUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Error from arithmetic on `DynQuantity`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynError {
+    /// The operands have different units, e.g. `5V + 1A`
+    DimensionMismatch { lhs: DynUnit, rhs: DynUnit },
+    /// No `define_rule!` relates the operands, e.g. `5V * 5V`
+    NoSuchRule { lhs: DynUnit, rhs: DynUnit },
+    /// No `define_rule!` has `lhs` as its output and `rhs` as an operand, e.g. `5V / 5s`
+    NoSuchDivRule { lhs: DynUnit, rhs: DynUnit },
+}
+
+impl fmt::Display for DynError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DynError::DimensionMismatch { lhs, rhs } => write!(f, "Can't combine '{}' with '{}'", lhs, rhs),
+            DynError::NoSuchRule { lhs, rhs } => write!(f, "No rule for '{}' * '{}'", lhs, rhs),
+            DynError::NoSuchDivRule { lhs, rhs } => write!(f, "No rule for '{}' / '{}'", lhs, rhs),
+        }
+    }
+}
+
+impl core::error::Error for DynError {}
+
+/// A `Number` in base units (kilograms for `Mass`) tagged with a runtime unit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynQuantity {
+    pub value: Number,
+    pub unit: DynUnit,
+}
+
+impl DynQuantity {
+    pub fn new<N: Into<Number>>(value: N, unit: DynUnit) -> Self {
+        Self { value: value.into(), unit }
+    }
+
+    /// Sum of two quantities with the same unit. Not `ops::Add`, since it can fail.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, rhs: Self) -> Result<Self, DynError> {
+        if self.unit != rhs.unit {
+            return Err(DynError::DimensionMismatch { lhs: self.unit, rhs: rhs.unit });
+        }
+        Ok(Self::new(self.value + rhs.value, self.unit))
+    }
+
+    /// `add` for callers working in `ParseError`, e.g. after parsing both
+    /// operands. A mismatch is `ParseError::Dimension`, naming both units.
+    pub fn try_add(self, other: Self) -> Result<Self, ParseError> {
+        self.add(other).map_err(ParseError::Dimension)
+    }
+
+    /// Product of two quantities, with the output unit looked up in the same
+    /// rules as the static `Mul` impls. Like those, operand order matters:
+    /// `Ω * A` is a voltage but `A * Ω` has no rule.
+    #[allow(clippy::should_implement_trait)]
+    pub fn mul(self, rhs: Self) -> Result<Self, DynError> {
+        let (output, _, _) = super::ops::RULES
+            .iter()
+            .find(|(_, lhs, r)| *lhs == self.unit && *r == rhs.unit)
+            .ok_or(DynError::NoSuchRule { lhs: self.unit, rhs: rhs.unit })?;
+        Ok(Self::new(self.value * rhs.value, *output))
+    }
+
+    /// Quotient of two quantities, found by running the `mul` rules backwards:
+    /// `V / A` is a resistance and `V / Ω` a current.
+    #[allow(clippy::should_implement_trait)]
+    pub fn div(self, rhs: Self) -> Result<Self, DynError> {
+        let unit = super::ops::RULES
+            .iter()
+            .filter(|(output, _, _)| *output == self.unit)
+            .find_map(|(_, lhs, r)| {
+                if *lhs == rhs.unit {
+                    Some(*r)
+                } else if *r == rhs.unit {
+                    Some(*lhs)
+                } else {
+                    None
+                }
+            })
+            .ok_or(DynError::NoSuchDivRule { lhs: self.unit, rhs: rhs.unit })?;
+        Ok(Self::new(self.value / rhs.value, unit))
+    }
+}
+
+/// Evaluate `"<quantity> <op> <quantity>"`, e.g. `"3.3kΩ * 2A"`, where `op` is
+/// `*`, `/` or `+` surrounded by spaces (so `"2m/s"` stays one quantity).
+/// A lone quantity evaluates to itself. Units that don't combine give
+/// `ParseError::Dimension`.
+pub fn eval_expr(s: &str) -> Result<DynQuantity, ParseError> {
+    let result = if let Some((lhs, rhs)) = s.split_once(" * ") {
+        lhs.parse::<DynQuantity>()?.mul(rhs.parse()?)
+    } else if let Some((lhs, rhs)) = s.split_once(" / ") {
+        lhs.parse::<DynQuantity>()?.div(rhs.parse()?)
+    } else if let Some((lhs, rhs)) = s.split_once(" + ") {
+        lhs.parse::<DynQuantity>()?.add(rhs.parse()?)
+    } else {
+        return s.parse();
+    };
+    result.map_err(ParseError::Dimension)
+}
+
+impl FromStr for DynQuantity {
+    type Err = ParseError;
+
+    /// Parse e.g. `"3.3kΩ"` by matching the longest unit symbol at the end of
+    /// the string, so `"2m/s"` is a velocity rather than a time.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let unit = DynUnit::ALL
+            .iter()
+            .filter(|unit| s.ends_with(unit.name()))
+            .max_by_key(|unit| unit.name().len())
+            .ok_or_else(|| ParseError::UnknownUnit(s.to_string()))?;
+        let value: Number = s[..s.len() - unit.name().len()].parse()?;
+        Ok(Self::new(super::rescale(value, unit.symbol_factor()), *unit))
+    }
+}
+
+impl fmt::Display for DynQuantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", super::rescale(self.value, 1. / self.unit.symbol_factor()), self.unit)
+    }
+}
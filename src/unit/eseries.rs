@@ -0,0 +1,108 @@
+use super::{Resistance, Unit, UnitNumber};
+#[cfg(feature = "std")]
+use crate::Number;
+
+/// IEC 60063 preferred-number series, as used for resistor and capacitor values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ESeries {
+    E6,
+    E12,
+    E24,
+    E96,
+}
+
+// Values per decade in hundredths, so e.g. 3.3kΩ is built from the exact integer 330
+const E6: [u16; 6] = [100, 150, 220, 330, 470, 680];
+
+const E12: [u16; 12] = [100, 120, 150, 180, 220, 270, 330, 390, 470, 560, 680, 820];
+
+const E24: [u16; 24] = [
+    100, 110, 120, 130, 150, 160, 180, 200, 220, 240, 270, 300,
+    330, 360, 390, 430, 470, 510, 560, 620, 680, 750, 820, 910,
+];
+
+const E96: [u16; 96] = [
+    100, 102, 105, 107, 110, 113, 115, 118, 121, 124, 127, 130,
+    133, 137, 140, 143, 147, 150, 154, 158, 162, 165, 169, 174,
+    178, 182, 187, 191, 196, 200, 205, 210, 215, 221, 226, 232,
+    237, 243, 249, 255, 261, 267, 274, 280, 287, 294, 301, 309,
+    316, 324, 332, 340, 348, 357, 365, 374, 383, 392, 402, 412,
+    422, 432, 442, 453, 464, 475, 487, 499, 511, 523, 536, 549,
+    562, 576, 590, 604, 619, 634, 649, 665, 681, 698, 715, 732,
+    750, 768, 787, 806, 825, 845, 866, 887, 909, 931, 953, 976,
+];
+
+impl ESeries {
+    /// From coarsest to finest
+    pub const ALL: [ESeries; 4] = [ESeries::E6, ESeries::E12, ESeries::E24, ESeries::E96];
+
+    /// The values of one decade in hundredths, starting at `100` (1.00)
+    pub fn values(self) -> &'static [u16] {
+        match self {
+            ESeries::E6 => &E6,
+            ESeries::E12 => &E12,
+            ESeries::E24 => &E24,
+            ESeries::E96 => &E96,
+        }
+    }
+
+    /// The component tolerance the series is designed for, as a fraction: ±20% for E6
+    /// down to ±1% for E96
+    pub fn tolerance(self) -> f64 {
+        match self {
+            ESeries::E6 => 0.2,
+            ESeries::E12 => 0.1,
+            ESeries::E24 => 0.05,
+            ESeries::E96 => 0.01,
+        }
+    }
+}
+
+/// `hundredths × 10^(exp - 2)`, dividing for negative powers so that e.g. 0.33 is exact
+#[cfg(feature = "std")]
+fn scaled(hundredths: u16, exp: i32) -> f64 {
+    let exp = exp - 2;
+    if exp >= 0 {
+        hundredths as f64 * 10f64.powi(exp)
+    } else {
+        hundredths as f64 / 10f64.powi(-exp)
+    }
+}
+
+impl<U: Unit> UnitNumber<U> {
+    /// The closest preferred value in `series`, by ratio rather than difference, so
+    /// `3.4kΩ` snaps to `3.3kΩ` in E24. Zero, negative and non-finite values are returned as is.
+    #[cfg(feature = "std")]
+    pub fn nearest_e_series(&self, series: ESeries) -> Self {
+        let x = self.to_f64();
+        if !(x.is_finite() && x > 0.) {
+            return *self;
+        }
+
+        let decade = x.log10().floor() as i32;
+        let candidates = series.values().iter()
+            .map(|&v| scaled(v, decade))
+            .chain(core::iter::once(scaled(100, decade + 1)));
+        let nearest = candidates
+            .min_by(|a, b| (x / a).ln().abs().total_cmp(&(x / b).ln().abs()))
+            .unwrap_or(x);
+        Self::new(Number::from_f64(nearest))
+    }
+}
+
+impl Resistance {
+    /// The nearest standard value and the coarsest series that has it, for BOMs.
+    /// A series only counts if its nearest value is within ±1% (the finest, E96,
+    /// tolerance) of `self`: every value is within ±20% of some E6 value, so the
+    /// series' own tolerance would put nearly everything in E6. Values that no
+    /// series matches saturate to their nearest E96 value.
+    #[cfg(feature = "std")]
+    pub fn classify_standard(&self) -> (Resistance, ESeries) {
+        let max_error = ESeries::E96.tolerance();
+        ESeries::ALL
+            .iter()
+            .map(|&series| (self.nearest_e_series(series), series))
+            .find(|(nearest, _)| self.rel_error(*nearest).to_f64() <= max_error)
+            .unwrap_or((self.nearest_e_series(ESeries::E96), ESeries::E96))
+    }
+}
@@ -1,17 +1,45 @@
 mod units;
 mod ops;
+mod range;
+mod rate;
+mod dynamic;
+mod eseries;
+mod unit_complex;
+pub mod as_struct;
 
 use core::fmt;
-use std::{fmt::Debug, marker::PhantomData, str::FromStr};
-use crate::Number;
+use core::{fmt::Debug, marker::PhantomData, str::FromStr};
+use alloc::{format, string::{String, ToString}, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(not(feature = "approx-eq"))]
+use core::hash::{Hash, Hasher};
+use crate::{Number, ParseError, Suffix};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 pub use units::*;
+pub use ops::*;
+pub use range::*;
+pub use rate::*;
+pub use dynamic::*;
+pub use eseries::*;
+pub use unit_complex::*;
 
 pub trait Unit : PartialEq + Eq + Clone + Copy + Debug {
     fn name() -> &'static str;
+
+    /// Base units per `name()`. Only `Mass` overrides it: it stores kilograms,
+    /// the SI base, but uses the symbol "g" so that prefixes read "500g", "1.5Mg".
+    fn symbol_factor() -> f64 {
+        1.0
+    }
+}
+
+/// `n × factor`, keeping `n`'s suffix when the factor is 1
+fn rescale(n: Number, factor: f64) -> Number {
+    if factor == 1. { n } else { Number::from_f64(n.to_f64() * factor) }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy)]
 pub struct UnitNumber<U> {
     number: Number,
     unit: PhantomData<U>,
@@ -30,6 +58,52 @@ impl<U: Unit> UnitNumber<U> {
         self.number
     }
 
+    /// The value in units of the symbol `U::name()`, as displayed. It only
+    /// differs from `value()` for `Mass`, which stores kilograms but shows grams.
+    pub fn symbol_value(&self) -> Number {
+        rescale(self.number, 1. / U::symbol_factor())
+    }
+
+    /// Build from a value in units of the symbol, e.g. `Mass::from_symbol_value(num!(500))`
+    /// is 500g. The same as `new` for every other unit.
+    pub fn from_symbol_value(number: Number) -> Self {
+        Self::new(rescale(number, U::symbol_factor()))
+    }
+
+    /// The value expressed in the given suffix scaling of the symbol, e.g. volts → millivolts.
+    pub fn in_suffix(&self, suffix: Suffix) -> f64 {
+        self.symbol_value().to_f64() / suffix.factor()
+    }
+
+    /// The same quantity with its internal `Number` rescaled to use `suffix`.
+    pub fn with_suffix(self, suffix: Suffix) -> Self {
+        Self::from_symbol_value(Number::new(self.in_suffix(suffix), suffix))
+    }
+
+    /// The mantissa and prefixed unit symbol after picking the natural suffix,
+    /// e.g. `3300V` gives `(3.3, "KV")`. The symbol is built at runtime, so it's
+    /// returned as an owned `String`.
+    pub fn rescaled_to_display(&self) -> (f64, String) {
+        let n = Number::from_f64(self.symbol_value().to_f64());
+        (n.value, format!("{}{}", n.suffix.name(), U::name()))
+    }
+
+    /// The base value as a `[1, 10)` coefficient and power of ten, plus the unit
+    /// symbol, e.g. `3.3kV` gives `(3.3, 3, "V")`. See `Number::frexp10`.
+    #[cfg(feature = "std")]
+    pub fn scientific_parts(&self) -> (f64, i32, &'static str) {
+        let (mantissa, exp) = self.symbol_value().frexp10();
+        (mantissa, exp, U::name())
+    }
+
+    /// Look up `key` in a map of unit strings (e.g. loaded config) and parse it.
+    /// Errors name the key, whether it is missing or fails to parse.
+    #[cfg(feature = "std")]
+    pub fn from_map_field(map: &HashMap<String, String>, key: &str) -> Result<Self, ParseError> {
+        let value = map.get(key).ok_or_else(|| ParseError::MissingField(key.to_string()))?;
+        value.parse().map_err(|err| ParseError::InvalidField { key: key.to_string(), source: Box::new(err) })
+    }
+
     pub fn is_nan(self) -> bool {
         self.number.is_nan()
     }
@@ -38,13 +112,80 @@ impl<U: Unit> UnitNumber<U> {
         self.number.is_finite()
     }
 
+    #[cfg(feature = "std")]
     pub fn powf(self, exp: f64) -> Self {
         Self::new(self.number.powf(exp))
     }
 
+    #[cfg(feature = "std")]
     pub fn atan2(self, other: f64) -> Self {
         Self::new(self.number.atan2(other))
     }
+
+    /// `None` if the sum is not finite. See `Number::checked_add`.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.number.checked_add(rhs.number).map(Self::new)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.number.checked_sub(rhs.number).map(Self::new)
+    }
+
+    /// Scale by a plain number, `None` if the result is not finite.
+    pub fn checked_mul(self, rhs: Number) -> Option<Self> {
+        self.number.checked_mul(rhs).map(Self::new)
+    }
+
+    pub fn checked_div(self, rhs: Number) -> Option<Self> {
+        self.number.checked_div(rhs).map(Self::new)
+    }
+
+    /// Display with the precision configured for this unit in `cfg`, if any.
+    pub fn display_config(&self, cfg: &UnitConfig) -> impl fmt::Display {
+        ConfiguredDisplay { value: *self, precision: cfg.precision_of::<U>() }
+    }
+
+    /// Linear interpolation towards `other`, e.g. for voltage sweeps. See `Number::lerp`.
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        Self::new(self.number.lerp(other.number, t))
+    }
+
+    /// `|self - other|`, e.g. the error of a measurement against its expected value.
+    pub fn abs_diff(self, other: Self) -> Self {
+        Self::new(Number::from_f64((self.to_f64() - other.to_f64()).abs()))
+    }
+
+    /// Fractional error `|self - expected| / |expected|`, so 5% is `0.05`.
+    /// A zero `expected` gives infinity, or NaN if `self` is zero too.
+    pub fn rel_error(self, expected: Self) -> Number {
+        Number::from_f64(self.abs_diff(expected).to_f64() / expected.to_f64().abs())
+    }
+
+    #[cfg(feature = "std")]
+    pub fn round_dp(&self, places: i32) -> Self {
+        Self::new(self.number.round_dp(places))
+    }
+
+    /// Restrict the value to `[min, max]`. Like `Ord::clamp` this panics if `min > max`,
+    /// but it only needs `PartialOrd`, so it's also available with `approx-eq`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min <= max, "clamp requires min <= max");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    pub fn min(self, other: Self) -> Self {
+        if other < self { other } else { self }
+    }
+
+    pub fn max(self, other: Self) -> Self {
+        if other >= self { other } else { self }
+    }
 }
 
 macro_rules! impl_f64_like_method {
@@ -57,6 +198,14 @@ macro_rules! impl_f64_like_method {
 
 impl<U: Unit> UnitNumber<U> {
     impl_f64_like_method!(abs);
+    impl_f64_like_method!(recip);
+
+    impl_f64_like_method!(to_degrees);
+    impl_f64_like_method!(to_radians);
+}
+
+#[cfg(feature = "std")]
+impl<U: Unit> UnitNumber<U> {
     impl_f64_like_method!(ceil);
     impl_f64_like_method!(floor);
     impl_f64_like_method!(round);
@@ -68,8 +217,7 @@ impl<U: Unit> UnitNumber<U> {
     impl_f64_like_method!(ln);
     impl_f64_like_method!(log10);
     impl_f64_like_method!(log2);
-    impl_f64_like_method!(recip);
-    
+
     impl_f64_like_method!(sin);
     impl_f64_like_method!(cos);
     impl_f64_like_method!(tan);
@@ -80,32 +228,130 @@ impl<U: Unit> UnitNumber<U> {
     impl_f64_like_method!(sinh);
     impl_f64_like_method!(cosh);
     impl_f64_like_method!(tanh);
+}
 
-    impl_f64_like_method!(to_degrees);
-    impl_f64_like_method!(to_radians);
+// Equality and hashing go through the base value of the inner `Number`,
+// so `1kV == 1000V`. The unit marker is not part of the hash. Like `Number`,
+// `Eq`, `Ord` and `Hash` are unavailable with the `approx-eq` feature.
+impl<U: Unit> PartialEq for UnitNumber<U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.number == other.number
+    }
+}
+
+#[cfg(not(feature = "approx-eq"))]
+impl<U: Unit> Eq for UnitNumber<U> {}
+
+#[cfg(not(feature = "approx-eq"))]
+impl<U: Unit> Hash for UnitNumber<U> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.number.hash(state);
+    }
 }
 
 impl<U: Unit> fmt::Display for UnitNumber<U> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Some(p) = f.precision() {
-            write!(f, "{:.*}{}", p, self.number, U::name())
-        } else {
-            write!(f, "{}{}", self.number, U::name())
+        let number = self.symbol_value();
+        let s = match (f.precision(), f.alternate()) {
+            (Some(p), true) => format!("{:#.*}{}", p, number, U::name()),
+            (Some(p), false) => format!("{:.*}{}", p, number, U::name()),
+            (None, true) => format!("{:#}{}", number, U::name()),
+            (None, false) => format!("{}{}", number, U::name()),
+        };
+        crate::number::pad_formatted(f, &s)
+    }
+}
+
+macro_rules! impl_exp_fmt {
+    ($trait:ident, $lit:literal, $lit_p:literal) => {
+        impl<U: Unit> fmt::$trait for UnitNumber<U> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let s = match f.precision() {
+                    Some(p) => format!($lit_p, p, self.symbol_value()),
+                    None => format!($lit, self.symbol_value()),
+                };
+                let s = if f.alternate() { crate::number::with_exp_sign(&s) } else { s };
+                crate::number::pad_formatted(f, &format!("{}{}", s, U::name()))
+            }
         }
+    };
+}
+
+impl_exp_fmt!(LowerExp, "{:e}", "{:.*e}");
+impl_exp_fmt!(UpperExp, "{:E}", "{:.*E}");
+
+/// Per-unit display precision for reports, keyed by unit symbol:
+/// `UnitConfig::new().precision::<VoltageUnit>(2).precision::<CurrentUnit>(3)`.
+#[derive(Debug, Clone, Default)]
+pub struct UnitConfig {
+    precisions: Vec<(&'static str, usize)>,
+}
+
+impl UnitConfig {
+    pub const fn new() -> Self {
+        Self { precisions: Vec::new() }
+    }
+
+    pub fn precision<U: Unit>(mut self, digits: usize) -> Self {
+        self.precisions.retain(|(name, _)| *name != U::name());
+        self.precisions.push((U::name(), digits));
+        self
+    }
+
+    pub fn precision_of<U: Unit>(&self) -> Option<usize> {
+        self.precisions.iter().find(|(name, _)| *name == U::name()).map(|(_, digits)| *digits)
+    }
+}
+
+struct ConfiguredDisplay<U> {
+    value: UnitNumber<U>,
+    precision: Option<usize>,
+}
+
+impl<U: Unit> fmt::Display for ConfiguredDisplay<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self.precision {
+            Some(p) => format!("{:.*}", p, self.value),
+            None => format!("{}", self.value),
+        };
+        crate::number::pad_formatted(f, &s)
+    }
+}
+
+/// Object-safe view of a `UnitNumber`, for collections mixing several units
+pub trait ErasedUnit {
+    fn base(&self) -> f64;
+    fn symbol(&self) -> &'static str;
+    fn display(&self) -> String;
+}
+
+impl<U: Unit> ErasedUnit for UnitNumber<U> {
+    fn base(&self) -> f64 {
+        self.to_f64()
+    }
+
+    fn symbol(&self) -> &'static str {
+        U::name()
+    }
+
+    fn display(&self) -> String {
+        self.to_string()
     }
 }
 
 impl<U: Unit> FromStr for UnitNumber<U> {
-    type Err = String;
+    type Err = ParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, Number::from_str)
+    }
+}
+
+impl<U: Unit> UnitNumber<U> {
+    fn parse_with(s: &str, parse_number: fn(&str) -> Result<Number, ParseError>) -> Result<Self, ParseError> {
         let s = s.trim();
-        if s.ends_with(U::name()) {
-            let number_len = s.len() - U::name().len();
-            let number_str = &s[..number_len];
-            let number: Number = FromStr::from_str(number_str)?;
-            Ok(Self::new(number))
-        } else {
-            Err(format!("Expect end with '{}'", U::name()))
+        match s.strip_suffix(U::name()) {
+            Some(number_str) => Ok(Self::from_symbol_value(parse_number(number_str)?)),
+            None => Err(ParseError::WrongUnit { expected: U::name() }),
         }
     }
 }
@@ -125,8 +371,38 @@ impl<'de, U: Unit> Deserialize<'de> for UnitNumber<U> {
     where
         D: Deserializer<'de>,
     {
+        // Like `Number`, accept the non-finite values that serialization writes
         let s = String::deserialize(deserializer)?;
-        Self::from_str(&s).map_err(serde::de::Error::custom)
+        Self::parse_with(&s, Number::parse_allow_non_finite).map_err(serde::de::Error::custom)
+    }
+}
+
+/// `Option<UnitNumber>` for `#[serde(with = "runit::unit::option_empty_as_none")]`,
+/// where a missing value is the empty string `""` (e.g. CSV-derived JSON).
+/// `null` is also read as `None`; `None` is written back as `""`.
+pub mod option_empty_as_none {
+    use alloc::string::String;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use super::{Unit, UnitNumber};
+
+    pub fn serialize<U: Unit, S: Serializer>(v: &Option<UnitNumber<U>>, serializer: S) -> Result<S::Ok, S::Error> {
+        match v {
+            Some(v) => v.serialize(serializer),
+            None => serializer.serialize_str(""),
+        }
+    }
+
+    pub fn deserialize<'de, U: Unit, D: Deserializer<'de>>(deserializer: D) -> Result<Option<UnitNumber<U>>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(s) if !s.trim().is_empty() => s.parse().map(Some).map_err(serde::de::Error::custom),
+            _ => Ok(None),
+        }
+    }
+}
+
+impl<U: Unit> Default for UnitNumber<U> {
+    fn default() -> Self {
+        UnitNumber::new(Number::zero())
     }
 }
 
@@ -1,36 +1,52 @@
-use crate::Number;
-use std::{cmp::Ordering, ops::{Add, Div, Mul, Neg, Rem, Sub}};
-use paste::paste;
+use alloc::{format, string::{String, ToString}, vec::Vec};
+use crate::{Complex, Number, ParseError, Suffix};
+use core::{cmp::Ordering, ops::{Add, Div, Mul, Neg, Rem, Sub}};
 use crate::unit::units::*;
 
-use super::{Unit, UnitNumber};
+use super::{DynUnit, Unit, UnitNumber};
 
-/// output = lhs * rhs
+/// output = lhs * rhs, for both `UnitNumber` and `UnitComplex`
+#[doc(hidden)]
 #[macro_export]
 macro_rules! impl_mul {
     ($output:ty, $lhs:ty, $rhs:ty) => {
-        paste! {
-            impl std::ops::Mul<crate::UnitNumber<[<$rhs Unit>]>> for crate::UnitNumber<[<$lhs Unit>]> {
-                type Output = crate::UnitNumber<[<$output Unit>]>;
-                fn mul(self, rhs: crate::UnitNumber<[<$rhs Unit>]>) -> Self::Output {
-                    let result = self.number * rhs.number;
-                    crate::UnitNumber::new(result)
+        $crate::paste::paste! {
+            impl core::ops::Mul<$crate::UnitNumber<[<$rhs Unit>]>> for $crate::UnitNumber<[<$lhs Unit>]> {
+                type Output = $crate::UnitNumber<[<$output Unit>]>;
+                fn mul(self, rhs: $crate::UnitNumber<[<$rhs Unit>]>) -> Self::Output {
+                    let result = self.value() * rhs.value();
+                    $crate::UnitNumber::new(result)
+                }
+            }
+
+            impl core::ops::Mul<$crate::UnitComplex<[<$rhs Unit>]>> for $crate::UnitComplex<[<$lhs Unit>]> {
+                type Output = $crate::UnitComplex<[<$output Unit>]>;
+                fn mul(self, rhs: $crate::UnitComplex<[<$rhs Unit>]>) -> Self::Output {
+                    $crate::UnitComplex::new(self.value() * rhs.value())
                 }
             }
         }
     };
 }
 
-/// output = lhs / rhs
+/// output = lhs / rhs, for both `UnitNumber` and `UnitComplex`
+#[doc(hidden)]
 #[macro_export]
 macro_rules! impl_div {
     ($output:ty, $lhs:ty, $rhs:ty) => {
-        paste! {
-            impl std::ops::Div<crate::UnitNumber<[<$rhs Unit>]>> for crate::UnitNumber<[<$lhs Unit>]> {
-                type Output = crate::UnitNumber<[<$output Unit>]>;
-                fn div(self, rhs: crate::UnitNumber<[<$rhs Unit>]>) -> Self::Output {
-                    let result = self.number / rhs.number;
-                    crate::UnitNumber::new(result)
+        $crate::paste::paste! {
+            impl core::ops::Div<$crate::UnitNumber<[<$rhs Unit>]>> for $crate::UnitNumber<[<$lhs Unit>]> {
+                type Output = $crate::UnitNumber<[<$output Unit>]>;
+                fn div(self, rhs: $crate::UnitNumber<[<$rhs Unit>]>) -> Self::Output {
+                    let result = self.value() / rhs.value();
+                    $crate::UnitNumber::new(result)
+                }
+            }
+
+            impl core::ops::Div<$crate::UnitComplex<[<$rhs Unit>]>> for $crate::UnitComplex<[<$lhs Unit>]> {
+                type Output = $crate::UnitComplex<[<$output Unit>]>;
+                fn div(self, rhs: $crate::UnitComplex<[<$rhs Unit>]>) -> Self::Output {
+                    $crate::UnitComplex::new(self.value() / rhs.value())
                 }
             }
         }
@@ -40,43 +56,457 @@ macro_rules! impl_div {
 /// output = lhs * rhs
 /// lhs = output / rhs
 /// rhs = output / lhs
+/// Internal: exported only so runit's own macros can reach it via `$crate`.
+#[doc(hidden)]
 #[macro_export]
 macro_rules! define_rule {
     ($output:ty, $lhs:ty, $rhs:ty) => {
-        impl_mul!($output, $lhs, $rhs);
-        impl_div!($lhs, $output, $rhs);
-        impl_div!($rhs, $output, $lhs);
+        $crate::impl_mul!($output, $lhs, $rhs);
+        $crate::impl_div!($lhs, $output, $rhs);
+        $crate::impl_div!($rhs, $output, $lhs);
+    };
+}
+
+/// Expands each `rules` entry with `define_rule!` and each `products` entry with
+/// `impl_mul!`, and records every product in `RULES` for `DynQuantity::mul`.
+macro_rules! builtin_rules {
+    (
+        rules: [$(($output:ident, $lhs:ident, $rhs:ident)),* $(,)?],
+        products: [$(($p_output:ident, $p_lhs:ident, $p_rhs:ident)),* $(,)?] $(,)?
+    ) => {
+        $(define_rule!($output, $lhs, $rhs);)*
+        $(impl_mul!($p_output, $p_lhs, $p_rhs);)*
+
+        /// `(output, lhs, rhs)` for every `lhs * rhs` product implemented above
+        pub(crate) const RULES: &[(DynUnit, DynUnit, DynUnit)] = &[
+            $((DynUnit::$output, DynUnit::$lhs, DynUnit::$rhs),)*
+            $((DynUnit::$p_output, DynUnit::$p_lhs, DynUnit::$p_rhs),)*
+        ];
     };
 }
 
-define_rule!(Voltage, Resistance, Current);    // V = R × I
-define_rule!(Power, Voltage, Current);         // P = V × I
-define_rule!(Energy, Power, Time);             // E = P × t
-define_rule!(Charge, Capacitance, Voltage);    // Q = C × V
-define_rule!(Charge, Current, Time);           // Q = C × V
-define_rule!(Current, Charge, Time);           // Q = I × t
-define_rule!(Length, Velocity, Time);          // S = V × T
- 
-define_rule!(Power, Force, Velocity);          // P = F × v
-define_rule!(Energy, Force, Length);           // E = F × d
-define_rule!(Force, Pressure, Area);           // F = P × A
+builtin_rules! {
+    rules: [
+        (Voltage, Resistance, Current),    // V = R × I
+        (Current, Voltage, Conductance),   // I = V × G
+        (Power, Voltage, Current),         // P = V × I
+        (Energy, Power, Time),             // E = P × t
+        (Charge, Capacitance, Voltage),    // Q = C × V
+        (Charge, Current, Time),           // Q = C × V
+        (Current, Charge, Time),           // Q = I × t
+        (Length, Velocity, Time),          // S = V × T
+
+        (Power, Force, Velocity),          // P = F × v
+        (Energy, Force, Length),           // E = F × d
+        (Force, Pressure, Area),           // F = P × A
+        (Force, Mass, Accel),              // F = m × a
 
-define_rule!(MagneticFlux, FluxDensity, Area); // Φ = B × A
-define_rule!(MagneticFlux, Voltage, Time);     // Φ = V × t
+        (MagneticFlux, FluxDensity, Area), // Φ = B × A
+        (MagneticFlux, Voltage, Time),     // Φ = V × t
+        (MagneticFlux, Inductance, Current), // Φ = L × I
+    ],
+    products: [
+        (Area, Length, Length),
+    ],
+}
 
-impl_mul!(Area, Length, Length);
 impl_div!(Length, Area, Length);
 
+/// A unit whose product with itself has a rule, e.g. `Length² = Area`
+pub trait Squared {
+    type Output;
+    fn squared(self) -> Self::Output;
+}
+
+/// The inverse of `Squared`, e.g. `√Area = Length`
+pub trait SqrtUnit {
+    type Output;
+    fn sqrt_unit(self) -> Self::Output;
+}
+
+/// square = base × base, reusing the `Mul` impl of that rule
+macro_rules! impl_square {
+    ($square:ty, $base:ty) => {
+        impl Squared for $base {
+            type Output = $square;
+            fn squared(self) -> $square {
+                self * self
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl SqrtUnit for $square {
+            type Output = $base;
+            fn sqrt_unit(self) -> $base {
+                <$base>::new(Number::from_f64(self.to_f64().sqrt()))
+            }
+        }
+    };
+}
+
+impl_square!(Area, Length);
+
 impl Frequency {
+    /// μ0, the vacuum permeability in H/m
+    #[cfg(feature = "std")]
+    const MU_0: f64 = 4e-7 * core::f64::consts::PI;
+
     pub fn to_period(&self) -> Time {
         Time::new(1. / self.number)
     }
+
+    /// ω = 2π × f, in rad/s
+    pub fn angular(&self) -> Number {
+        Number::from_f64(core::f64::consts::TAU * self.to_f64())
+    }
+
+    /// δ = √(ρ / (π × f × μ0 × μr)), with the resistivity ρ in Ω·m.
+    #[cfg(feature = "std")]
+    pub fn skin_depth(&self, resistivity: Number, rel_permeability: f64) -> Length {
+        let mu = Self::MU_0 * rel_permeability;
+        let depth = (resistivity.to_f64() / (core::f64::consts::PI * self.to_f64() * mu)).sqrt();
+        Length::new(Number::from_f64(depth))
+    }
 }
 
 impl Time {
     pub fn to_frquency(&self) -> Frequency {
         Frequency::new(1. / self.number)
     }
+
+    /// Whole nanoseconds (rounded to nearest), for exact `core::time::Duration` construction.
+    /// Returns `None` for negative, non-finite or out-of-range times.
+    #[cfg(feature = "std")]
+    pub fn try_into_duration_nanos(&self) -> Option<u128> {
+        let nanos = (self.to_f64() * 1e9).round();
+        if nanos.is_finite() && nanos >= 0. && nanos <= u128::MAX as f64 {
+            Some(nanos as u128)
+        } else {
+            None
+        }
+    }
+}
+
+impl Current {
+    /// P = I² × R
+    pub fn power_in(&self, r: Resistance) -> Power {
+        Power::new(self.number * self.number * r.number)
+    }
+}
+
+impl Voltage {
+    /// P = V² / R
+    pub fn power_across(&self, r: Resistance) -> Power {
+        Power::new(self.number * self.number / r.number)
+    }
+}
+
+/// 1 / (1/x₁ + 1/x₂ + ...). A zero value gives zero, an empty input gives infinity.
+fn reciprocal_sum(values: impl Iterator<Item = f64>) -> Number {
+    Number::from_f64(1. / values.map(|x| 1. / x).sum::<f64>())
+}
+
+impl Resistance {
+    /// R = R₁ × R₂ / (R₁ + R₂). A zero resistance shorts the pair, giving 0Ω.
+    pub fn parallel(self, other: Resistance) -> Resistance {
+        Resistance::new(reciprocal_sum([self.to_f64(), other.to_f64()].into_iter()))
+    }
+
+    pub fn series(self, other: Resistance) -> Resistance {
+        self + other
+    }
+
+    /// Z = R + 0j in ohms, the same at every frequency
+    pub fn impedance_at(&self, _f: Frequency) -> Complex {
+        Complex::new(self.number, 0)
+    }
+
+    /// All of `rs` in parallel. An empty slice is an open circuit, i.e. infinite resistance.
+    pub fn parallel_all(rs: &[Resistance]) -> Resistance {
+        Resistance::new(reciprocal_sum(rs.iter().map(|r| r.to_f64())))
+    }
+}
+
+impl Inductance {
+    /// V = L × dI / dt
+    pub fn voltage_for(&self, di: Current, dt: Time) -> Voltage {
+        Voltage::new(self.number * di.number / dt.number)
+    }
+
+    /// E = ½ × L × I²
+    pub fn energy_at(&self, i: Current) -> Energy {
+        let i = i.to_f64();
+        Energy::new(Number::from_f64(0.5 * self.to_f64() * i * i))
+    }
+
+    /// Z = jωL in ohms
+    pub fn impedance_at(&self, f: Frequency) -> Complex {
+        Complex::new(0, f.angular() * self.number)
+    }
+
+    /// f = 1 / (2π × √(LC)). A zero L or C gives an infinite frequency.
+    #[cfg(feature = "std")]
+    pub fn resonant_freq(&self, c: Capacitance) -> Frequency {
+        let lc = self.to_f64() * c.to_f64();
+        Frequency::new(Number::from_f64(1. / (core::f64::consts::TAU * lc.sqrt())))
+    }
+}
+
+impl Capacitance {
+    /// E = ½ × C × V²
+    pub fn energy_at(&self, v: Voltage) -> Energy {
+        let v = v.to_f64();
+        Energy::new(Number::from_f64(0.5 * self.to_f64() * v * v))
+    }
+
+    /// Z = 1 / (jωC) = -j / (ωC) in ohms. At 0Hz the imaginary part is -inf.
+    pub fn impedance_at(&self, f: Frequency) -> Complex {
+        Complex::new(0, Number::from_f64(-1. / (f.angular().to_f64() * self.to_f64())))
+    }
+
+    pub fn parallel(self, other: Capacitance) -> Capacitance {
+        self + other
+    }
+
+    /// C = C₁ × C₂ / (C₁ + C₂)
+    pub fn series(self, other: Capacitance) -> Capacitance {
+        Capacitance::new(reciprocal_sum([self.to_f64(), other.to_f64()].into_iter()))
+    }
+}
+
+impl Power {
+    /// dBm = 10 × log10(P / 1mW). Zero power gives -inf and negative power gives NaN.
+    #[cfg(feature = "std")]
+    pub fn to_dbm(&self) -> f64 {
+        10. * (self.to_f64() / 1e-3).log10()
+    }
+
+    #[cfg(feature = "std")]
+    pub fn from_dbm(dbm: f64) -> Power {
+        Power::new(Number::from_f64(1e-3 * 10f64.powf(dbm / 10.)))
+    }
+}
+
+impl Energy {
+    /// P = E / t, the average power over the given interval
+    pub fn average_power(&self, over: Time) -> Power {
+        *self / over
+    }
+}
+
+// Temperature is stored in kelvin. Celsius and Fahrenheit are affine scales,
+// so these conversions work on base values instead of suffix factors.
+impl Temperature {
+    const CELSIUS_OFFSET: f64 = 273.15;
+
+    pub fn from_celsius(c: f64) -> Temperature {
+        Temperature::new(Number::from_f64(c + Self::CELSIUS_OFFSET))
+    }
+
+    pub fn to_celsius(&self) -> f64 {
+        self.to_f64() - Self::CELSIUS_OFFSET
+    }
+
+    pub fn from_fahrenheit(f: f64) -> Temperature {
+        Self::from_celsius((f - 32.) * 5. / 9.)
+    }
+
+    pub fn to_fahrenheit(&self) -> f64 {
+        self.to_celsius() * 9. / 5. + 32.
+    }
+}
+
+impl Area {
+    const HECTARE: f64 = 1e4;
+    /// International acre in m²
+    const ACRE: f64 = 4046.8564224;
+
+    pub fn as_hectare(&self) -> Number {
+        Number::from_f64(self.to_f64() / Self::HECTARE)
+    }
+
+    pub fn as_acre(&self) -> Number {
+        Number::from_f64(self.to_f64() / Self::ACRE)
+    }
+
+    pub fn from_hectare(ha: Number) -> Area {
+        Area::new(Number::from_f64(ha.to_f64() * Self::HECTARE))
+    }
+
+    pub fn from_acre(acre: Number) -> Area {
+        Area::new(Number::from_f64(acre.to_f64() * Self::ACRE))
+    }
+}
+
+impl NoiseDensity {
+    /// Total noise voltage over a flat bandwidth: density × √bw
+    #[cfg(feature = "std")]
+    pub fn integrated(&self, bw: Frequency) -> Voltage {
+        Voltage::new(Number::from_f64(self.to_f64() * bw.to_f64().sqrt()))
+    }
+}
+
+// Velocity is stored in m/s; km/h and mph are only accepted and emitted as text.
+impl Velocity {
+    const MPS_PER_KMH: f64 = 1. / 3.6;
+    const MPS_PER_MPH: f64 = 0.44704;
+
+    /// Parse `"36km/h"`, `"30mph"` or any plain `"10m/s"` string, converting to m/s.
+    /// SI prefixes are only accepted on m/s: `"1kkm/h"` is an error.
+    pub fn from_str_compound(s: &str) -> Result<Velocity, ParseError> {
+        let s = s.trim();
+        let (number, factor) = if let Some(n) = s.strip_suffix("km/h") {
+            (n, Self::MPS_PER_KMH)
+        } else if let Some(n) = s.strip_suffix("mph") {
+            (n, Self::MPS_PER_MPH)
+        } else {
+            return s.parse();
+        };
+
+        let number: Number = number.parse()?;
+        if number.suffix != Suffix::None {
+            return Err(ParseError::InvalidNumber(s.to_string()));
+        }
+        Ok(Velocity::new(Number::from_f64(number.to_f64() * factor)))
+    }
+
+    /// e.g. `"36km/h"`; the value is never prefixed, so 400m/s is `"1440km/h"`
+    pub fn to_kmh_string(&self) -> String {
+        format!("{}km/h", self.to_f64() * 3.6)
+    }
+
+    /// e.g. `"60mph"`
+    pub fn to_mph_string(&self) -> String {
+        format!("{}mph", self.to_f64() / Self::MPS_PER_MPH)
+    }
+}
+
+// Named apart from the generic `UnitNumber::to_degrees`/`sin`/..., which
+// apply to the stored value and keep the `Angle` type.
+impl Angle {
+    pub fn from_degrees(d: f64) -> Angle {
+        Angle::new(Number::from_f64(d.to_radians()))
+    }
+
+    /// The angle in degrees as a plain `f64`
+    pub fn in_degrees(&self) -> f64 {
+        self.to_f64().to_degrees()
+    }
+
+    /// Normalize the angle into [0, 2π)
+    #[cfg(feature = "std")]
+    pub fn wrap(&self) -> Angle {
+        Angle::new(Number::from_f64(self.to_f64().rem_euclid(core::f64::consts::TAU)))
+    }
+
+    /// sin θ as a dimensionless `Number`
+    #[cfg(feature = "std")]
+    pub fn sine(&self) -> Number {
+        Number::from_f64(self.to_f64().sin())
+    }
+
+    #[cfg(feature = "std")]
+    pub fn cosine(&self) -> Number {
+        Number::from_f64(self.to_f64().cos())
+    }
+
+    #[cfg(feature = "std")]
+    pub fn tangent(&self) -> Number {
+        Number::from_f64(self.to_f64().tan())
+    }
+}
+
+/// Σx² over the base values. U² has no unit type, so the result is a plain `Number`.
+pub fn sum_of_squares<U: Unit>(values: &[UnitNumber<U>]) -> Number {
+    Number::from_f64(values.iter().map(|v| v.to_f64() * v.to_f64()).sum::<f64>())
+}
+
+/// Root-mean-square √(mean(x²)) of the base values. An empty slice gives NaN.
+#[cfg(feature = "std")]
+pub fn rms<U: Unit>(values: &[UnitNumber<U>]) -> UnitNumber<U> {
+    let sum_sq = sum_of_squares(values).to_f64();
+    UnitNumber::new(Number::from_f64((sum_sq / values.len() as f64).sqrt()))
+}
+
+/// Q = (1 / R) × √(L / C) of a series RLC circuit. A zero R gives an infinite Q.
+#[cfg(feature = "std")]
+pub fn series_rlc_q(r: Resistance, l: Inductance, c: Capacitance) -> f64 {
+    (l.to_f64() / c.to_f64()).sqrt() / r.to_f64()
+}
+
+/// Thévenin equivalent `(Voc, Rth)` of a source from two load points, fitting `V = Voc - I × Rth`.
+/// If both points have the same current the fit is undefined and both results are NaN.
+pub fn thevenin(p1: (Voltage, Current), p2: (Voltage, Current)) -> (Voltage, Resistance) {
+    let (v1, i1) = (p1.0.to_f64(), p1.1.to_f64());
+    let (v2, i2) = (p2.0.to_f64(), p2.1.to_f64());
+    if i1 == i2 {
+        return (Voltage::new(f64::NAN), Resistance::new(f64::NAN));
+    }
+
+    let rth = (v1 - v2) / (i2 - i1);
+    let voc = v1 + i1 * rth;
+    (Voltage::new(Number::from_f64(voc)), Resistance::new(Number::from_f64(rth)))
+}
+
+/// Total power of a power spectral density (in W/Hz) by trapezoidal integration over frequency.
+/// Points are sorted by frequency first, so they may be given in any order; fewer than two
+/// points integrate to zero.
+pub fn integrate_psd(points: &[(Frequency, Number)]) -> Power {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.0.to_f64().total_cmp(&b.0.to_f64()));
+
+    let total: f64 = points
+        .windows(2)
+        .map(|w| {
+            let (f0, p0) = w[0];
+            let (f1, p1) = w[1];
+            (f1.to_f64() - f0.to_f64()) * (p0.to_f64() + p1.to_f64()) / 2.
+        })
+        .sum();
+
+    Power::new(Number::from_f64(total))
+}
+
+/// Remove 2π jumps between successive phase samples, so that the result is continuous.
+fn unwrap_phase(phase: &[f64]) -> Vec<f64> {
+    use core::f64::consts::{PI, TAU};
+
+    let mut offset = 0.;
+    let mut unwrapped = Vec::with_capacity(phase.len());
+    for (k, &p) in phase.iter().enumerate() {
+        if k > 0 {
+            let mut d = p - phase[k - 1];
+            while d > PI {
+                d -= TAU;
+                offset -= TAU;
+            }
+            while d < -PI {
+                d += TAU;
+                offset += TAU;
+            }
+        }
+        unwrapped.push(p + offset);
+    }
+    unwrapped
+}
+
+/// Group delay `τ = -dφ/dω` from phase response samples sorted by frequency.
+/// The phase is unwrapped first, then each neighbouring pair gives one delay at
+/// their mid frequency, so the result has one point fewer than the input.
+pub fn group_delay(points: &[(Frequency, Angle)]) -> Vec<(Frequency, Time)> {
+    let phase: Vec<f64> = points.iter().map(|(_, a)| a.to_f64()).collect();
+    let phase = unwrap_phase(&phase);
+
+    points
+        .windows(2)
+        .zip(phase.windows(2))
+        .map(|(f, p)| {
+            let (f0, f1) = (f[0].0.to_f64(), f[1].0.to_f64());
+            let dw = core::f64::consts::TAU * (f1 - f0);
+            let delay = -(p[1] - p[0]) / dw;
+            (Frequency::new(Number::from_f64((f0 + f1) / 2.)), Time::new(Number::from_f64(delay)))
+        })
+        .collect()
 }
 
 impl Mul<Time> for Frequency {
@@ -93,6 +523,20 @@ impl Mul<Frequency> for Time {
     }
 }
 
+impl<U: Unit> UnitNumber<U> {
+    /// `self / other` as a typed `Ratio`; plain `/` between the same unit gives a bare `Number`.
+    pub fn ratio(self, other: Self) -> Ratio {
+        Ratio::new(self / other)
+    }
+}
+
+impl Ratio {
+    /// The ratio scaled by 100, e.g. `0.25` gives `25`.
+    pub fn as_percent(&self) -> Number {
+        Number::from_f64(self.to_f64() * 100.)
+    }
+}
+
 impl<U: Unit> Div<UnitNumber<U>> for UnitNumber<U> {
     type Output = Number;
     fn div(self, rhs: UnitNumber<U>) -> Self::Output {
@@ -171,6 +615,10 @@ impl<U: Unit> Div<Number> for UnitNumber<U> {
     }
 }
 
+// Number / UnitNumber<U> is intentionally not implemented: the result would carry
+// the reciprocal dimension (e.g. 1/V), which has no unit type. Use `recip` on the
+// value explicitly if an untyped reciprocal is really wanted.
+
 impl<U: Unit> Rem<UnitNumber<U>> for UnitNumber<U> {
     type Output = Self;
     fn rem(self, rhs: UnitNumber<U>) -> Self::Output {
@@ -180,12 +628,14 @@ impl<U: Unit> Rem<UnitNumber<U>> for UnitNumber<U> {
 
 //==================== Cmp and Eq =========================//
 
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl<U: Unit> PartialOrd for UnitNumber<U> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.number.partial_cmp(&other.number)
     }
 }
 
+#[cfg(not(feature = "approx-eq"))]
 impl<U: Unit> Ord for UnitNumber<U> {
     fn cmp(&self, other: &Self) -> Ordering {
         self.partial_cmp(other).unwrap()
@@ -0,0 +1,59 @@
+use core::marker::PhantomData;
+use crate::Number;
+use super::{Unit, UnitNumber};
+
+/// Iterator over `start, start + step, ...` up to and including `stop`.
+/// Each value is recomputed as `start + n × step`, so long sweeps don't drift.
+#[derive(Debug, Clone)]
+pub struct UnitRange<U> {
+    start: f64,
+    stop: f64,
+    step: f64,
+    n: u64,
+    unit: PhantomData<U>,
+}
+
+impl<U: Unit> UnitNumber<U> {
+    /// Sweep from `start` to `stop` (inclusive) by `step`, which may be negative.
+    /// A zero or NaN step, or one pointing away from `stop`, gives an empty sweep.
+    pub fn range(start: Self, stop: Self, step: Self) -> UnitRange<U> {
+        UnitRange {
+            start: start.to_f64(),
+            stop: stop.to_f64(),
+            step: step.to_f64(),
+            n: 0,
+            unit: PhantomData,
+        }
+    }
+
+    /// `start..=end` by `step`, for `for v in Voltage::range_step(..)` loops
+    /// (`core::iter::Step` is unstable). Same sweep as `UnitNumber::range`.
+    pub fn range_step(start: Self, end: Self, step: Self) -> impl Iterator<Item = Self> {
+        Self::range(start, end, step)
+    }
+}
+
+impl<U: Unit> Iterator for UnitRange<U> {
+    type Item = UnitNumber<U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.step == 0. || self.step.is_nan() {
+            return None;
+        }
+
+        let value = self.start + self.n as f64 * self.step;
+        // Allow for rounding so that e.g. 0 → 0.3 by 0.1 still ends on 0.3
+        let slack = self.step.abs() * 1e-9;
+        let in_range = if self.step > 0. {
+            value <= self.stop + slack
+        } else {
+            value >= self.stop - slack
+        };
+        if !in_range {
+            return None;
+        }
+
+        self.n += 1;
+        Some(UnitNumber::new(Number::from_f64(value)))
+    }
+}
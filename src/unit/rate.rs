@@ -0,0 +1,53 @@
+use core::{fmt, marker::PhantomData, ops::Mul};
+use alloc::format;
+use crate::Number;
+use super::{Unit, UnitNumber};
+
+/// A ratio of two units without a dedicated type, e.g. a slew rate in V/s.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rate<N, D> {
+    number: Number,
+    unit: PhantomData<(N, D)>,
+}
+
+impl<N: Unit, D: Unit> Rate<N, D> {
+    pub fn new<T: Into<Number>>(number: T) -> Self {
+        Self { number: number.into(), unit: PhantomData }
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.number.to_f64()
+    }
+
+    pub fn value(&self) -> Number {
+        self.number
+    }
+}
+
+impl<N: Unit> UnitNumber<N> {
+    /// `self / den` as a `Rate`, e.g. `u!(1 V).per(u!(1 us))` is `1MV/s`.
+    pub fn per<D: Unit>(self, den: UnitNumber<D>) -> Rate<N, D> {
+        Rate::new(Number::from_f64(self.to_f64() / den.to_f64()))
+    }
+}
+
+/// numerator = rate × denominator
+impl<N: Unit, D: Unit> Mul<UnitNumber<D>> for Rate<N, D> {
+    type Output = UnitNumber<N>;
+    fn mul(self, rhs: UnitNumber<D>) -> Self::Output {
+        UnitNumber::new(self.number * rhs.number)
+    }
+}
+
+impl<N: Unit, D: Unit> fmt::Display for Rate<N, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Shown per symbol unit, which only differs from the base for `Mass`
+        let factor = D::symbol_factor() / N::symbol_factor();
+        let number = if factor == 1. { self.number } else { Number::from_f64(self.to_f64() * factor) };
+        let s = match f.precision() {
+            Some(p) => format!("{:.*}{}/{}", p, number, N::name(), D::name()),
+            None => format!("{}{}/{}", number, N::name(), D::name()),
+        };
+        crate::number::pad_formatted(f, &s)
+    }
+}
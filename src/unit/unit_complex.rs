@@ -0,0 +1,107 @@
+use core::{fmt, marker::PhantomData, ops::{Add, Neg, Sub}, str::FromStr};
+use alloc::{format, string::{String, ToString}};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::{Complex, ParseError};
+use super::{Unit, UnitNumber};
+
+/// A complex-valued quantity such as an AC phasor voltage or an impedance.
+/// Displays as the complex number followed by the unit symbol, e.g. `"3+4jΩ"`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitComplex<U> {
+    complex: Complex,
+    unit: PhantomData<U>,
+}
+
+impl<U: Unit> UnitComplex<U> {
+    pub fn new<C: Into<Complex>>(complex: C) -> Self {
+        Self { complex: complex.into(), unit: PhantomData }
+    }
+
+    pub fn value(&self) -> Complex {
+        self.complex
+    }
+
+    pub fn from_parts(re: UnitNumber<U>, im: UnitNumber<U>) -> Self {
+        Self::new(Complex::new(re.value(), im.value()))
+    }
+
+    pub fn re(&self) -> UnitNumber<U> {
+        UnitNumber::new(self.complex.re)
+    }
+
+    pub fn im(&self) -> UnitNumber<U> {
+        UnitNumber::new(self.complex.im)
+    }
+}
+
+/// `c × factor`, keeping the parts' suffixes when the factor is 1
+fn rescale(c: Complex, factor: f64) -> Complex {
+    if factor == 1. { c } else { c.scale(factor) }
+}
+
+impl<U: Unit> From<UnitNumber<U>> for UnitComplex<U> {
+    fn from(value: UnitNumber<U>) -> Self {
+        Self::new(Complex::new(value.value(), 0))
+    }
+}
+
+impl<U: Unit> Add for UnitComplex<U> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.complex + rhs.complex)
+    }
+}
+
+impl<U: Unit> Sub for UnitComplex<U> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.complex - rhs.complex)
+    }
+}
+
+impl<U: Unit> Neg for UnitComplex<U> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.complex)
+    }
+}
+
+impl<U: Unit> fmt::Display for UnitComplex<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let complex = rescale(self.complex, 1. / U::symbol_factor());
+        let s = match f.precision() {
+            Some(p) => format!("{:.*}{}", p, complex, U::name()),
+            None => format!("{}{}", complex, U::name()),
+        };
+        crate::number::pad_formatted(f, &s)
+    }
+}
+
+impl<U: Unit> FromStr for UnitComplex<U> {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().strip_suffix(U::name()) {
+            Some(complex_str) => Ok(Self::new(rescale(complex_str.parse()?, U::symbol_factor()))),
+            None => Err(ParseError::WrongUnit { expected: U::name() }),
+        }
+    }
+}
+
+impl<U: Unit> Serialize for UnitComplex<U> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de, U: Unit> Deserialize<'de> for UnitComplex<U> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
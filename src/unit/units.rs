@@ -1,50 +1,79 @@
-use super::{Unit, UnitNumber};
-use paste::paste;
-
-macro_rules! define_unit {
+/// Defines a unit marker `<Name>Unit` and the quantity alias `<Name>`.
+///
+/// Relations between units are plain functions outside runit: every quantity
+/// is a `UnitNumber<_>`, so operator impls for new units would break the
+/// orphan rules (runit's own `define_rule!` is internal for that reason).
+///
+/// ```
+/// runit::unit!(Amount, "mol");
+/// runit::unit!(Concentration, "mol/L");
+/// runit::unit!(Volume, "L");
+///
+/// let n = Amount::new(runit::num!(2.5 m));
+/// assert_eq!(n.to_string(), "2.5mmol");
+///
+/// fn amount(c: Concentration, v: Volume) -> Amount { Amount::new(c.value() * v.value()) }
+/// assert_eq!(amount(Concentration::new(2), Volume::new(runit::num!(500 m))).to_string(), "1mol");
+/// ```
+#[macro_export]
+macro_rules! unit {
     ($name:ident, $symbol:literal) => {
-        paste! {
+        $crate::unit!($name, $symbol, 1.0);
+    };
+    ($name:ident, $symbol:literal, $factor:literal) => {
+        $crate::paste::paste! {
             #[derive(Debug, PartialEq, Eq, Clone, Copy)]
             pub struct [<$name Unit>];
 
-            impl Unit for [<$name Unit>] {
+            impl $crate::Unit for [<$name Unit>] {
                 fn name() -> &'static str {
                     $symbol
                 }
+
+                fn symbol_factor() -> f64 {
+                    $factor
+                }
             }
 
-            pub type $name = UnitNumber<[<$name Unit>]>;
+            pub type $name = $crate::UnitNumber<[<$name Unit>]>;
         }
     };
 }
 
-define_unit!(Voltage, "V");
-define_unit!(Current, "A");
-define_unit!(Resistance, "Ω");
-define_unit!(Capacitance, "F");
-define_unit!(Inductance, "H");
-define_unit!(Charge, "Q");
-define_unit!(Power, "W");
-define_unit!(Energy, "J");
-define_unit!(Time, "s");
-define_unit!(Frequency, "Hz");
-define_unit!(Length, "m");
-define_unit!(Area, "m²");
-define_unit!(Force, "N");
-define_unit!(Pressure, "Pa");
-define_unit!(MagneticFlux, "Wb");
-define_unit!(FluxDensity, "T");
-define_unit!(Conductance, "S");
-define_unit!(Velocity, "m/s");
-define_unit!(Accel, "m/s²");
-define_unit!(Temperature, "K");
-define_unit!(Angle, "rad");
+crate::unit!(Voltage, "V");
+crate::unit!(Current, "A");
+crate::unit!(Resistance, "Ω");
+crate::unit!(Capacitance, "F");
+crate::unit!(Inductance, "H");
+crate::unit!(Charge, "Q");
+crate::unit!(Power, "W");
+crate::unit!(Energy, "J");
+crate::unit!(Time, "s");
+crate::unit!(Frequency, "Hz");
+crate::unit!(Length, "m");
+crate::unit!(Area, "m²");
+crate::unit!(Force, "N");
+crate::unit!(Pressure, "Pa");
+crate::unit!(MagneticFlux, "Wb");
+crate::unit!(FluxDensity, "T");
+crate::unit!(Conductance, "S");
+crate::unit!(Velocity, "m/s");
+crate::unit!(Accel, "m/s²");
+crate::unit!(Temperature, "K");
+crate::unit!(Angle, "rad");
+// Values are stored in kilograms, the SI base, so that `Force = Mass × Accel`
+// holds, but the symbol is "g" so prefixes never stack up as in "500mkg".
+crate::unit!(Mass, "g", 1e-3);
+// Noise spectral density; the prefix scales the leading V, e.g. "10nV/√Hz"
+crate::unit!(NoiseDensity, "V/√Hz");
+// Dimensionless ratio of two same-unit quantities, e.g. a gain; displays as a bare number
+crate::unit!(Ratio, "");
 
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use crate::{i, num, r, u, vel};
+    use crate::{acc, e, g, i, mass, num, r, u, v, vel, Number, Suffix};
 
     use super::*;
 
@@ -70,7 +99,35 @@ mod tests {
     fn test_invalid_unit() {
         let result = Voltage::from_str("5.6A");
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "Expect end with 'V'");
+        assert_eq!(result.unwrap_err().to_string(), "Expect end with 'V'");
+    }
+
+    #[test]
+    fn test_multibyte_prefix_and_unit() {
+        let r = Resistance::from_str("1.2µΩ").unwrap();
+        assert_eq!(r.value(), Number::new(1.2, Suffix::Micro));
+        assert_eq!(Resistance::from_str("1.2μΩ").unwrap(), r);
+
+        assert!(Resistance::from_str("µ").is_err());
+        assert!(Area::from_str("1.2µ²").is_err());
+    }
+
+    #[test]
+    fn test_unicode_micro_parsing() {
+        let c = Capacitance::from_str("2.2µF").unwrap();
+        assert_eq!(c.value(), Number::new(2.2, Suffix::Micro));
+
+        let c = Capacitance::from_str("2.2μF").unwrap();
+        assert_eq!(c, u!(2.2 uF));
+    }
+
+    #[test]
+    fn test_parse_error_variants() {
+        use crate::ParseError;
+
+        assert_eq!(Voltage::from_str("5.6A"), Err(ParseError::WrongUnit { expected: "V" }));
+        assert_eq!(Voltage::from_str("5.6xV"), Err(ParseError::UnknownSuffix("x".to_string())));
+        assert!(matches!(Current::from_str("?A"), Err(ParseError::InvalidNumber(_))));
     }
 
     #[test]
@@ -79,6 +136,37 @@ mod tests {
         assert_eq!(v.number, num!(1.2 u));
     }
 
+    #[test]
+    fn test_u_macro_extended_prefixes() {
+        let c = u!(1.5 fF);
+        assert_eq!(c.value(), Number::new(1.5, Suffix::Femto));
+        assert_eq!(c.to_string(), "1.5fF");
+
+        // A bare "T" is tesla; tera needs the unit after it
+        assert_eq!(u!(1.5 T), FluxDensity::new(num!(1.5)));
+        assert_eq!(u!(2 TW).value(), Number::new(2.0, Suffix::Tera));
+        assert_eq!(u!(3 aF).value(), Number::new(3.0, Suffix::Atto));
+    }
+
+    #[test]
+    fn test_u_macro_negative_and_expr() {
+        let v = u!(-3.3 V);
+        assert_eq!(v, -u!(3.3 V));
+        assert_eq!(v.to_string(), "-3.3V");
+
+        let i = u!(-2 mA);
+        assert_eq!(i.value(), Number::new(-2.0, Suffix::Milli));
+
+        let a = 1.0;
+        let b = 2.0;
+        let v = u!((a + b) kV);
+        assert_eq!(v.value(), Number::new(3.0, Suffix::Kilo));
+
+        let n = 4;
+        let r = u!(n Ω);
+        assert_eq!(r.to_string(), "4Ω");
+    }
+
     #[test]
     fn test_same_unit_add_sub() {
         let q1 = u!(10 mQ); 
@@ -125,6 +213,67 @@ mod tests {
     }
 
     #[test]
+    fn test_suffix_scaling() {
+        let v = u!(1.5 V);
+        assert_eq!(v.in_suffix(Suffix::Milli), 1500.0);
+        assert_eq!(v.in_suffix(Suffix::None), 1.5);
+
+        let mv = v.with_suffix(Suffix::Milli);
+        assert_eq!(mv.value().suffix, Suffix::Milli);
+        assert_eq!(mv.to_string(), "1500mV");
+        assert_eq!(mv.to_f64(), 1.5);
+
+        let kv = u!(3300 V).with_suffix(Suffix::Kilo);
+        assert_eq!(kv.to_string(), "3.3KV");
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn test_round_dp() {
+        let v = u!(3.14159 V).round_dp(2);
+        assert_eq!(v.to_string(), "3.14V");
+
+        let i = u!(1.23456 mA).round_dp(5);
+        assert_eq!(i.to_string(), "1.23mA");
+    }
+
+    #[test]
+    fn test_clamp_min_max() {
+        let lo = u!(0.0 A);
+        let hi = u!(20.0 mA);
+        assert_eq!(u!(35.0 mA).clamp(lo, hi).to_string(), "20mA");
+        assert_eq!((-u!(5.0 mA)).clamp(lo, hi).to_string(), "0A");
+        assert_eq!(u!(4.5 mA).clamp(lo, hi).to_string(), "4.5mA");
+
+        let v = u!(5.0 V).clamp(u!(0.0 V), u!(3.3 V));
+        assert_eq!(v, u!(3.3 V));
+
+        assert_eq!(u!(1.0 mA).min(u!(2.0 uA)).to_string(), "2uA");
+        assert_eq!(u!(1.0 mA).max(u!(2.0 uA)).to_string(), "1mA");
+    }
+
+    #[test]
+    fn test_with_suffix_keeps_prefix_through_arithmetic() {
+        let i = Current::new(num!(1.0)).with_suffix(Suffix::Milli);
+        assert_eq!(i.to_string(), "1000mA");
+        assert_eq!(i.to_f64(), 1.0);
+        assert_eq!(i, u!(1.0 A));
+    }
+
+    #[test]
+    fn test_rescaled_to_display() {
+        let v = Voltage::new(3300.0);
+        assert_eq!(v.rescaled_to_display(), (3.3, "KV".to_string()));
+
+        let i = Current::new(num!(0.002));
+        assert_eq!(i.rescaled_to_display(), (2.0, "mA".to_string()));
+
+        let r = u!(47 Ω);
+        assert_eq!(r.rescaled_to_display(), (47.0, "Ω".to_string()));
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
     fn test_display_default() {
         let v = Voltage::new(3.1415926);
         assert_eq!(v.to_string(), "3.1415926V");
@@ -137,6 +286,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_display_precision() {
         let v = Voltage::new(3.1415926);
         assert_eq!(format!("{:.2}", v), "3.14V");
@@ -210,6 +360,52 @@ mod tests {
         assert_eq!(p.value(), num!(6.0));
     }
 
+    #[test]
+    fn test_power_dissipation() {
+        let p = i!(2.0).power_in(r!(5.0));
+        assert_eq!(p, u!(20.0 W));
+
+        let p = v!(10.0).power_across(r!(5.0));
+        assert_eq!(p, u!(20.0 W));
+    }
+
+    #[test]
+    fn test_inductor_voltage() {
+        let l = u!(2.0 H);
+        let v = l.voltage_for(u!(1.0 A), u!(0.5 s));
+        assert_eq!(v, u!(4.0 V));
+
+        let v = u!(10.0 uH).voltage_for(u!(2.0 mA), u!(1.0 us));
+        assert!((v.to_f64() - 20e-3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_reactive_stored_energy() {
+        let e = u!(2.0 F).energy_at(u!(3.0 V));
+        assert_eq!(e, u!(9.0 J));
+
+        let e = u!(4.0 H).energy_at(u!(2.0 A));
+        assert_eq!(e, u!(8.0 J));
+    }
+
+    #[test]
+    fn test_lc_resonant_freq() {
+        let f = u!(1 uH).resonant_freq(u!(1 nF));
+        assert!((f.to_f64() - 5.0329e6).abs() < 1e2);
+        assert_eq!(f.value().suffix, Suffix::Mega);
+
+        assert!(u!(0 H).resonant_freq(u!(1 nF)).to_f64().is_infinite());
+    }
+
+    #[test]
+    fn test_series_rlc_q() {
+        // √(10µH / 1nF) = 100Ω, so Q = 100 / 10
+        let q = crate::series_rlc_q(u!(10 Ω), u!(10 uH), u!(1 nF));
+        assert!((q - 10.0).abs() < 1e-9);
+
+        assert!(crate::series_rlc_q(u!(0 Ω), u!(10 uH), u!(1 nF)).is_infinite());
+    }
+
     #[test]
     fn test_energy_from_power_time() {
         let p = Power::new(num!(5.0));
@@ -223,6 +419,24 @@ mod tests {
         assert_eq!(e.value(), num!(50.0 m));
     }
 
+    #[test]
+    fn test_power_dbm() {
+        assert!(u!(1.0 mW).to_dbm().abs() < 1e-12);
+        assert!((u!(1.0 W).to_dbm() - 30.0).abs() < 1e-12);
+        assert_eq!(u!(0.0 W).to_dbm(), f64::NEG_INFINITY);
+        assert!((-u!(1.0 W)).to_dbm().is_nan());
+
+        assert!((Power::from_dbm(0.0).to_f64() - 1e-3).abs() < 1e-15);
+        assert!((Power::from_dbm(30.0).to_f64() - 1.0).abs() < 1e-12);
+        assert!((Power::from_dbm(u!(2.5 W).to_dbm()).to_f64() - 2.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_average_power() {
+        let p = e!(10.0).average_power(u!(2.0 s));
+        assert_eq!(p, u!(5.0 W));
+    }
+
     #[test]
     fn test_charge_from_capacitance_voltage() {
         let c = Capacitance::new(num!(1.5));
@@ -273,6 +487,19 @@ mod tests {
         assert_eq!(s, u!(500 m));
     }
 
+    #[test]
+    fn test_force_from_mass_accel() {
+        let f = mass!(2 K) * acc!(3.0);
+        assert_eq!(f, Force::new(num!(6.0)));
+        assert_eq!(f.to_string(), "6N");
+
+        let m = u!(2.0 kg);
+        assert_eq!(m.to_f64(), 2.0);
+        assert_eq!(m.to_string(), "2Kg");
+        assert_eq!(f / m, acc!(3.0));
+        assert_eq!(f / acc!(3.0), m);
+    }
+
     #[test]
     fn test_pressure_from_force_area() {
         let f = Force::new(num!(100.0));
@@ -289,6 +516,359 @@ mod tests {
         assert_eq!(phi.value(), num!(6.0));
     }
 
+    #[test]
+    fn test_time_duration_nanos() {
+        assert_eq!(u!(1.5 ms).try_into_duration_nanos(), Some(1_500_000));
+        assert_eq!(u!(2 s).try_into_duration_nanos(), Some(2_000_000_000));
+        assert_eq!(u!(3.3 ns).try_into_duration_nanos(), Some(3));
+        assert_eq!((-u!(1 s)).try_into_duration_nanos(), None);
+        assert_eq!(Time::new(f64::INFINITY).try_into_duration_nanos(), None);
+    }
+
+    #[test]
+    fn test_temperature_scales() {
+        let t = Temperature::from_celsius(0.0);
+        assert!((t.to_f64() - 273.15).abs() < 1e-9);
+        assert!(t.to_celsius().abs() < 1e-9);
+
+        let t = Temperature::from_celsius(100.0);
+        assert!((t.to_fahrenheit() - 212.0).abs() < 1e-9);
+
+        let t = Temperature::from_fahrenheit(32.0);
+        assert!((t.to_f64() - 273.15).abs() < 1e-9);
+
+        let t = u!(300 K);
+        assert!((t.to_celsius() - 26.85).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_scientific_parts() {
+        assert_eq!(Voltage::new(num!(3.3 k)).scientific_parts(), (3.3, 3, "V"));
+        assert_eq!(u!(1 mA).scientific_parts(), (1.0, -3, "A"));
+    }
+
+    #[test]
+    fn test_from_map_field() {
+        use std::collections::HashMap;
+
+        let map = HashMap::from([
+            ("voltage".to_string(), "3.3kV".to_string()),
+            ("current".to_string(), "5V".to_string()),
+        ]);
+
+        assert_eq!(Voltage::from_map_field(&map, "voltage").unwrap(), v!(3.3 k));
+        assert_eq!(
+            Voltage::from_map_field(&map, "power"),
+            Err(crate::ParseError::MissingField("power".to_string()))
+        );
+
+        let err = Current::from_map_field(&map, "current").unwrap_err();
+        assert_eq!(
+            err,
+            crate::ParseError::InvalidField {
+                key: "current".to_string(),
+                source: Box::new(crate::ParseError::WrongUnit { expected: "A" }),
+            }
+        );
+        assert_eq!(err.to_string(), "Invalid value for 'current': Expect end with 'A'");
+    }
+
+    #[test]
+    fn test_erased_unit_collection() {
+        use crate::ErasedUnit;
+
+        let values: Vec<Box<dyn ErasedUnit>> = vec![Box::new(u!(3.3 V)), Box::new(u!(20 mA))];
+        let report: Vec<String> = values.iter().map(|v| v.display()).collect();
+        assert_eq!(report, ["3.3V", "20mA"]);
+
+        assert_eq!(values[0].symbol(), "V");
+        assert_eq!(values[1].symbol(), "A");
+        assert_eq!(values[1].base(), 0.02);
+    }
+
+    #[test]
+    fn test_custom_unit() {
+        crate::unit!(Amount, "mol");
+        crate::unit!(Molarity, "mol/m³");
+        crate::unit!(Volume, "m³");
+        crate::define_rule!(Amount, Molarity, Volume);
+
+        let n = Amount::new(num!(2.5 m));
+        assert_eq!(n.to_string(), "2.5mmol");
+        assert_eq!(Amount::from_str("3kmol").unwrap().value(), num!(3 k));
+
+        let c = Molarity::new(num!(5));
+        let n = c * Volume::new(num!(2 m));
+        assert_eq!(n.to_string(), "10mmol");
+        assert_eq!(n / c, Volume::new(num!(2 m)));
+    }
+
+    #[test]
+    fn test_noise_density() {
+        use crate::nd;
+
+        let d = NoiseDensity::from_str("10nV/√Hz").unwrap();
+        assert_eq!(d, nd!(10 n));
+        assert_eq!(d.to_string(), "10nV/√Hz");
+
+        let v = d.integrated(u!(1 kHz));
+        assert!((v.to_f64() - 10e-9 * 1000f64.sqrt()).abs() < 1e-18);
+        assert_eq!(v.value().suffix, Suffix::Nano);
+    }
+
+    #[test]
+    fn test_skin_depth() {
+        // Copper, ρ ≈ 17.2nΩ·m
+        let d = u!(1 MHz).skin_depth(num!(17.2 n), 1.0);
+        assert!((d.to_f64() - 66e-6).abs() < 0.5e-6);
+        assert_eq!(d.value().suffix, Suffix::Micro);
+    }
+
+    #[test]
+    fn test_range_step_loop() {
+        let mut seen = Vec::new();
+        for v in Voltage::range_step(v!(0.0), v!(5.0), v!(1.0)) {
+            seen.push(v);
+        }
+        assert_eq!(seen, [v!(0), v!(1), v!(2), v!(3), v!(4), v!(5)]);
+
+        assert_eq!(Voltage::range_step(v!(5.0), v!(0.0), v!(-2.0)).collect::<Vec<_>>(), [v!(5), v!(3), v!(1)]);
+    }
+
+    #[test]
+    fn test_rate_per() {
+        let slew = u!(1 V).per(u!(1 us));
+        assert_eq!(slew.to_string(), "1MV/s");
+        assert_eq!(slew.to_f64(), 1e6);
+
+        let v: Voltage = slew * u!(2.5 us);
+        assert_eq!(v, u!(2.5 V));
+
+        assert_eq!(format!("{:.1}", u!(3 mA).per(u!(2 s))), "1.5mA/s");
+    }
+
+    #[test]
+    fn test_sum_of_squares() {
+        // 1² + 2² + 0.5² = 5.25
+        let sum = crate::sum_of_squares(&[u!(1 V), u!(-2 V), u!(500 mV)]);
+        assert_eq!(sum, num!(5.25));
+
+        assert_eq!(crate::sum_of_squares(&[u!(3 mA), u!(4 mA)]), num!(25 u));
+        assert_eq!(crate::sum_of_squares::<VoltageUnit>(&[]), num!(0));
+    }
+
+    #[test]
+    fn test_rms() {
+        let v = crate::rms(&[u!(1 V), u!(-1 V), u!(1 V), u!(-1 V)]);
+        assert_eq!(v, u!(1 V));
+
+        // √((9 + 16) / 2)
+        let v = crate::rms(&[u!(3 mV), u!(4 mV)]);
+        assert!((v.to_f64() - 12.5f64.sqrt() * 1e-3).abs() < 1e-15);
+
+        assert!(crate::rms::<VoltageUnit>(&[]).is_nan());
+    }
+
+    #[test]
+    fn test_unit_range() {
+        let sweep: Vec<Voltage> = Voltage::range(u!(0 V), u!(1 V), u!(0.25 V)).collect();
+        assert_eq!(sweep, [u!(0 V), u!(0.25 V), u!(0.5 V), u!(0.75 V), u!(1 V)]);
+
+        let down: Vec<Voltage> = Voltage::range(u!(1 V), u!(0 V), u!(-0.5 V)).collect();
+        assert_eq!(down, [u!(1 V), u!(0.5 V), u!(0 V)]);
+
+        // 0.3 / 0.1 isn't exact, but the sweep still ends on 0.3
+        assert_eq!(Time::range(u!(0 s), u!(0.3 s), u!(0.1 s)).count(), 4);
+        // stop isn't a whole number of steps away
+        assert_eq!(Current::range(u!(0 mA), u!(1 mA), u!(0.3 mA)).count(), 4);
+
+        assert_eq!(Voltage::range(u!(0 V), u!(1 V), u!(0 V)).count(), 0);
+        assert_eq!(Voltage::range(u!(0 V), u!(1 V), u!(-1 V)).count(), 0);
+    }
+
+    #[test]
+    fn test_unit_checked_arithmetic() {
+        assert_eq!(u!(1 V).checked_add(u!(500 mV)), Some(u!(1.5 V)));
+        assert_eq!(u!(1 V).checked_sub(Voltage::new(f64::NAN)), None);
+        assert_eq!(u!(2 mA).checked_mul(num!(3)), Some(u!(6 mA)));
+        assert_eq!(u!(2 mA).checked_div(num!(0)), None);
+    }
+
+    #[test]
+    fn test_velocity_compound_units() {
+        let v = Velocity::from_str_compound("36km/h").unwrap();
+        assert!((v.to_f64() - 10.0).abs() < 1e-12);
+
+        let v = Velocity::from_str_compound("60mph").unwrap();
+        assert!((v.to_f64() - 26.8224).abs() < 1e-9);
+
+        assert_eq!(Velocity::from_str_compound("3m/s").unwrap(), vel!(3));
+        assert!(Velocity::from_str_compound("3ft/s").is_err());
+
+        assert_eq!(vel!(10).to_kmh_string(), "36km/h");
+        assert_eq!(vel!(26.8224).to_mph_string(), "60mph");
+        assert_eq!(vel!(400).to_kmh_string(), "1440km/h");
+        assert_eq!(Velocity::from_str_compound("1440km/h").unwrap(), vel!(400));
+
+        assert!(Velocity::from_str_compound("1kkm/h").is_err());
+        assert!(Velocity::from_str_compound("2Mmph").is_err());
+        assert_eq!(Velocity::from_str_compound("1km/s").unwrap(), vel!(1 k));
+    }
+
+    #[test]
+    fn test_exp_format() {
+        assert_eq!(format!("{:e}", Voltage::new(1500.0)), "1.5e3V");
+        assert_eq!(format!("{:E}", u!(4.7 uF)), "4.7E-6F");
+        assert_eq!(format!("{:#e}", Voltage::new(1500.0)), "1.5e+3V");
+        assert_eq!(format!("{:#E}", u!(4.7 uF)), "4.7E-6F");
+        assert_eq!(format!("{:.1e}", u!(3.3 kΩ)), "3.3e3Ω");
+    }
+
+    #[test]
+    fn test_display_config() {
+        use crate::UnitConfig;
+
+        let cfg = UnitConfig::new().precision::<VoltageUnit>(2).precision::<CurrentUnit>(3);
+        assert_eq!(u!(3.3 V).display_config(&cfg).to_string(), "3.30V");
+        assert_eq!(u!(1.5 mA).display_config(&cfg).to_string(), "1.500mA");
+        assert_eq!(u!(10 kΩ).display_config(&cfg).to_string(), "10KΩ");
+        assert_eq!(u!(3.3 V).to_string(), "3.3V");
+
+        let cfg = cfg.precision::<VoltageUnit>(0);
+        assert_eq!(u!(3.3 V).display_config(&cfg).to_string(), "3V");
+        assert_eq!(format!("{:>6}|", u!(3.3 V).display_config(&cfg)), "    3V|");
+        assert_eq!(format!("{:*<8}|", u!(1.5 mA).display_config(&cfg)), "1.500mA*|");
+    }
+
+    #[test]
+    fn test_display_width_and_fill() {
+        assert_eq!(format!("{:>10}", u!(3.3 V)), "      3.3V");
+        assert_eq!(format!("{:<10}|", u!(3.3 V)), "3.3V      |");
+        assert_eq!(format!("{:010.2}", u!(3.3 mA)), "00003.30mA");
+        assert_eq!(format!("{:>#8}", Voltage::new(1e6)), "     1MV");
+        assert_eq!(format!("{:>8}", u!(10 kΩ)), "    10KΩ");
+    }
+
+    #[test]
+    fn test_alternate_display_normalizes() {
+        let v = Voltage::new(1e6);
+        assert_eq!(format!("{}", v), "1000000V");
+        assert_eq!(format!("{:#}", v), "1MV");
+
+        let i = Current::new(num!(1500 u));
+        assert_eq!(format!("{:.1}", i), "1500.0uA");
+        assert_eq!(format!("{:#.1}", i), "1.5mA");
+    }
+
+    #[test]
+    fn test_unit_lerp() {
+        assert_eq!(u!(0.0 V).lerp(u!(3.3 V), 0.0), u!(0.0 V));
+        assert_eq!(u!(0.0 V).lerp(u!(3.3 V), 0.5), u!(1.65 V));
+        assert_eq!(u!(0.0 V).lerp(u!(3.3 V), 1.0), u!(3.3 V));
+        assert_eq!(u!(1 mA).lerp(u!(2 mA), 2.0), u!(3 mA));
+    }
+
+    #[test]
+    fn test_squared_units() {
+        use crate::{a, m, SqrtUnit, Squared};
+
+        assert_eq!(m!(3.0).squared(), a!(9.0));
+        assert_eq!(m!(2 m).squared(), a!(4 u));
+        assert_eq!(a!(9.0).sqrt_unit(), m!(3.0));
+        assert_eq!(a!(4 u).sqrt_unit(), m!(2 m));
+    }
+
+    #[test]
+    fn test_area_land_units() {
+        let a = Area::new(num!(10 k));
+        assert_eq!(a.as_hectare(), num!(1));
+        assert_eq!(Area::from_hectare(num!(1)), a);
+
+        let acre = Area::from_acre(num!(2.5));
+        assert!((acre.to_f64() - 2.5 * 4046.8564224).abs() < 1e-9);
+        assert!((acre.as_acre().to_f64() - 2.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_angle_degrees() {
+        use std::f64::consts::PI;
+
+        let a = Angle::from_degrees(180.0);
+        assert!((a.to_f64() - PI).abs() < 1e-12);
+        assert!((a.in_degrees() - 180.0).abs() < 1e-12);
+        assert!((a.to_degrees().to_f64() - 180.0).abs() < 1e-12);
+        assert!((u!(180 V).to_radians().to_f64() - PI).abs() < 1e-12);
+
+        let a = Angle::new(3.0 * PI).wrap();
+        assert!((a.to_f64() - PI).abs() < 1e-12);
+
+        let a = Angle::from_degrees(-90.0).wrap();
+        assert!((a.in_degrees() - 270.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_angle_trig() {
+        let s = Angle::from_degrees(90.0).sine();
+        assert!((s.to_f64() - 1.0).abs() < 1e-12);
+
+        assert_eq!(Angle::new(num!(0.0)).cosine(), num!(1.0));
+        assert!(Angle::new(num!(0.0)).sine().is_zero());
+
+        let t = Angle::from_degrees(45.0).tangent();
+        assert!((t.to_f64() - 1.0).abs() < 1e-12);
+
+        // The generic methods are still there and keep the unit
+        let s: Angle = Angle::from_degrees(90.0).sin();
+        assert!((s.to_f64() - 1.0).abs() < 1e-12);
+        assert_eq!(Angle::new(num!(0.0)).cos(), Angle::new(num!(1.0)));
+    }
+
+    #[test]
+    fn test_thevenin() {
+        // Voc = 12V, Rth = 2Ω
+        let (voc, rth) = crate::thevenin((u!(10.0 V), u!(1.0 A)), (u!(6.0 V), u!(3.0 A)));
+        assert!((voc.to_f64() - 12.0).abs() < 1e-12);
+        assert!((rth.to_f64() - 2.0).abs() < 1e-12);
+
+        let (voc, rth) = crate::thevenin((u!(5.0 V), u!(1.0 A)), (u!(4.0 V), u!(1.0 A)));
+        assert!(voc.is_nan() && rth.is_nan());
+    }
+
+    #[test]
+    fn test_integrate_psd() {
+        // Flat 1nW/Hz from 1kHz to 11kHz is 10uW in total
+        let psd = num!(1.0 n);
+        let points: Vec<_> = (0..=10)
+            .map(|k| (Frequency::new(1e3 + 1e3 * k as f64), psd))
+            .rev()
+            .collect();
+        let p = crate::integrate_psd(&points);
+        assert!((p.to_f64() - 10e-6).abs() < 1e-15);
+
+        assert_eq!(crate::integrate_psd(&[(u!(1 kHz), psd)]), u!(0 W));
+    }
+
+    #[test]
+    fn test_group_delay_linear_phase() {
+        // φ = -2π × f × 1µs, wrapped into [0, 2π) as a phase meter would report it
+        let tau = 1e-6;
+        let points: Vec<_> = (1..=10)
+            .map(|k| {
+                let f = k as f64 * 300e3;
+                let phase = (-std::f64::consts::TAU * f * tau).rem_euclid(std::f64::consts::TAU);
+                (Frequency::new(f), Angle::new(phase))
+            })
+            .collect();
+
+        let delays = crate::group_delay(&points);
+        assert_eq!(delays.len(), 9);
+        for (f, t) in delays {
+            assert!(f > u!(300 kHz) && f < u!(3 MHz));
+            assert!((t.to_f64() - tau).abs() < 1e-15);
+        }
+        assert!(crate::group_delay(&points[..1]).is_empty());
+    }
+
     #[test]
     fn test_serialize_deserialize_unit_voltage() {
         let v = Voltage::from_str("5.0V").unwrap();
@@ -316,12 +896,123 @@ mod tests {
         assert_eq!(parsed, r);
     }
 
+    #[test]
+    fn test_serialize_deserialize_non_finite() {
+        let v = u!(0.0 V) / num!(0.0);
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "\"NaNV\"");
+        let parsed: Voltage = serde_json::from_str(&json).unwrap();
+        assert!(parsed.is_nan());
+
+        let i = u!(1.0 A) / num!(0.0);
+        let json = serde_json::to_string(&i).unwrap();
+        assert_eq!(json, "\"infA\"");
+        let parsed: Current = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, i);
+
+        let parsed: Current = serde_json::from_str("\"-infA\"").unwrap();
+        assert_eq!(parsed.to_f64(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_from_str_rejects_non_finite() {
+        assert!(Voltage::from_str("infV").is_err());
+        assert!(Current::from_str("NaNA").is_err());
+    }
+
     #[test]
     fn test_deserialize_invalid_unit() {
         let result: Result<Voltage, _> = serde_json::from_str("\"3.3A\""); // 单位错了
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_serialize_deserialize_as_struct() {
+        use crate::unit::as_struct;
+
+        let v = v!(3.3 k);
+        let mut json = Vec::new();
+        as_struct::serialize(&v, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        assert_eq!(json, r#"{"value":3.3,"suffix":"K","unit":"V"}"#);
+
+        let parsed: Voltage = as_struct::deserialize(&mut serde_json::Deserializer::from_str(&json)).unwrap();
+        assert_eq!(parsed, v);
+        assert_eq!(parsed.value().suffix, Suffix::Kilo);
+
+        let wrong_unit = r#"{"value":3.3,"suffix":"K","unit":"A"}"#;
+        let result: Result<Voltage, _> = as_struct::deserialize(&mut serde_json::Deserializer::from_str(wrong_unit));
+        assert!(result.is_err());
+
+        let lower_kilo = r#"{"value":3.3,"suffix":"k","unit":"V"}"#;
+        let parsed: Voltage = as_struct::deserialize(&mut serde_json::Deserializer::from_str(lower_kilo)).unwrap();
+        assert_eq!(parsed, v);
+
+        let bad_suffix = r#"{"value":3.3,"suffix":"x","unit":"V"}"#;
+        let result: Result<Voltage, _> = as_struct::deserialize(&mut serde_json::Deserializer::from_str(bad_suffix));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_option_empty_as_none() {
+        use crate::unit::option_empty_as_none;
+
+        let parse = |json: &str| -> Result<Option<Voltage>, _> {
+            option_empty_as_none::deserialize(&mut serde_json::Deserializer::from_str(json))
+        };
+        assert_eq!(parse("\"\"").unwrap(), None);
+        assert_eq!(parse("null").unwrap(), None);
+        assert_eq!(parse("\"3.3V\"").unwrap(), Some(v!(3.3)));
+        assert!(parse("\"3.3A\"").is_err());
+
+        let mut json = Vec::new();
+        option_empty_as_none::serialize::<VoltageUnit, _>(&None, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        assert_eq!(json, b"\"\"");
+    }
+
+    #[test]
+    fn test_scale_by_number_keeps_unit() {
+        let v: Voltage = v!(2.0) * num!(3.0);
+        assert_eq!(v, u!(6.0 V));
+
+        let v: Voltage = num!(3.0) * v!(2.0);
+        assert_eq!(v, u!(6.0 V));
+
+        let v: Voltage = v!(6.0) / num!(3.0);
+        assert_eq!(v, u!(2.0 V));
+    }
+
+    #[test]
+    fn test_eq_ignores_suffix() {
+        assert_eq!(u!(1.0 kV), u!(1000.0 V));
+        assert_eq!(u!(2.0 kA), Current::new(Number::new(2000.0, Suffix::None)));
+        assert_ne!(u!(1.0 kV), u!(1.0 V));
+    }
+
+    #[test]
+    #[cfg(not(feature = "approx-eq"))]
+    fn test_unit_number_as_map_key() {
+        use std::collections::HashMap;
+
+        let mut tolerances = HashMap::new();
+        tolerances.insert(u!(1.0 kΩ), "1%");
+        assert_eq!(tolerances.get(&u!(1000.0 Ω)), Some(&"1%"));
+        assert_eq!(tolerances.get(&u!(1.0 Ω)), None);
+    }
+
+    #[test]
+    fn test_default_derive() {
+        #[derive(Debug, Default)]
+        struct Config {
+            limit: Current,
+            supply: Voltage,
+        }
+
+        let cfg = Config::default();
+        assert_eq!(cfg.limit, u!(0 A));
+        assert_eq!(cfg.supply.to_string(), "0V");
+    }
+
     #[test]
     fn test_div_self() {
         let t1 = u!(100 s);
@@ -329,4 +1020,275 @@ mod tests {
         let s = t1 / t2;
         assert_eq!(s, num!(1))
     }
+
+    #[test]
+    fn test_dyn_quantity_add() {
+        use crate::unit::{DynError, DynQuantity, DynUnit};
+
+        let a = DynQuantity::new(num!(1.5), DynUnit::Voltage);
+        let b = DynQuantity::new(num!(500 m), DynUnit::Voltage);
+        let sum = a.add(b).unwrap();
+        assert_eq!(sum.unit, DynUnit::Voltage);
+        assert_eq!(sum.value, num!(2));
+        assert_eq!(sum.to_string(), "2V");
+
+        let i = DynQuantity::new(num!(1), DynUnit::Current);
+        assert_eq!(
+            a.add(i),
+            Err(DynError::DimensionMismatch { lhs: DynUnit::Voltage, rhs: DynUnit::Current })
+        );
+    }
+
+    #[test]
+    fn test_dyn_quantity_try_add() {
+        use crate::ParseError;
+        use crate::unit::{DynError, DynQuantity};
+
+        let a: DynQuantity = "1.5V".parse().unwrap();
+        let b: DynQuantity = "500mV".parse().unwrap();
+        assert_eq!(a.try_add(b).unwrap().to_string(), "2V");
+
+        let i: DynQuantity = "1A".parse().unwrap();
+        let err = a.try_add(i).unwrap_err();
+        assert!(matches!(err, ParseError::Dimension(DynError::DimensionMismatch { .. })));
+        assert_eq!(err.to_string(), "Can't combine 'V' with 'A'");
+    }
+
+    #[test]
+    fn test_dyn_quantity_from_str() {
+        use crate::ParseError;
+        use crate::unit::{DynQuantity, DynUnit};
+
+        let r: DynQuantity = "10kΩ".parse().unwrap();
+        assert_eq!(r.unit, DynUnit::Resistance);
+        assert_eq!(r.value, num!(10 k));
+
+        let v: DynQuantity = "5V".parse().unwrap();
+        assert_eq!(v, DynQuantity::new(num!(5), DynUnit::Voltage));
+
+        assert_eq!("1.2mA".parse::<DynQuantity>().unwrap().unit, DynUnit::Current);
+        assert_eq!("2m/s".parse::<DynQuantity>().unwrap().unit, DynUnit::Velocity);
+        assert_eq!("3m²".parse::<DynQuantity>().unwrap().unit, DynUnit::Area);
+        assert_eq!("3.3x".parse::<DynQuantity>(), Err(ParseError::UnknownUnit("3.3x".to_string())));
+    }
+
+    #[test]
+    fn test_dyn_quantity_div() {
+        use crate::unit::{DynError, DynQuantity, DynUnit};
+
+        let v = DynQuantity::new(num!(3), DynUnit::Voltage);
+        let i = DynQuantity::new(num!(1.5 m), DynUnit::Current);
+        let r = DynQuantity::new(num!(2 k), DynUnit::Resistance);
+        assert_eq!(v.div(i), Ok(r));
+        assert_eq!(v.div(r), Ok(i));
+
+        let t = DynQuantity::new(num!(1), DynUnit::Time);
+        assert_eq!(v.div(t), Err(DynError::NoSuchDivRule { lhs: DynUnit::Voltage, rhs: DynUnit::Time }));
+    }
+
+    #[test]
+    fn test_eval_expr() {
+        use crate::ParseError;
+        use crate::unit::{DynError, DynUnit, eval_expr};
+
+        let v = eval_expr("3.3kΩ * 2.0A").unwrap();
+        assert_eq!(v.unit, DynUnit::Voltage);
+        assert!((v.value.to_f64() - 6.6e3).abs() < 1e-9);
+
+        let i = eval_expr("5V / 2kΩ").unwrap();
+        assert_eq!(i.unit, DynUnit::Current);
+        assert_eq!(i.value, num!(2.5 m));
+
+        assert_eq!(eval_expr("4m/s * 2s").unwrap().unit, DynUnit::Length);
+        assert_eq!(eval_expr("1V + 500mV").unwrap().value, num!(1.5));
+        assert_eq!(eval_expr("10kΩ").unwrap().unit, DynUnit::Resistance);
+
+        assert_eq!(
+            eval_expr("1V + 1A"),
+            Err(ParseError::Dimension(DynError::DimensionMismatch { lhs: DynUnit::Voltage, rhs: DynUnit::Current }))
+        );
+        assert_eq!(eval_expr("1V + 1A").unwrap_err().to_string(), "Can't combine 'V' with 'A'");
+        assert!(matches!(eval_expr("1V * 1V"), Err(ParseError::Dimension(DynError::NoSuchRule { .. }))));
+        assert_eq!(eval_expr("1V * 3x"), Err(ParseError::UnknownUnit("3x".to_string())));
+    }
+
+    #[test]
+    fn test_dyn_quantity_mul() {
+        use crate::unit::{DynError, DynQuantity, DynUnit};
+
+        let r = DynQuantity::new(num!(2 k), DynUnit::Resistance);
+        let i = DynQuantity::new(num!(1.5 m), DynUnit::Current);
+        assert_eq!(r.mul(i), Ok(DynQuantity::new(num!(3), DynUnit::Voltage)));
+
+        let l = DynQuantity::new(num!(3), DynUnit::Length);
+        assert_eq!(l.mul(l).unwrap().unit, DynUnit::Area);
+
+        let v = DynQuantity::new(num!(5), DynUnit::Voltage);
+        assert_eq!(
+            v.mul(v),
+            Err(DynError::NoSuchRule { lhs: DynUnit::Voltage, rhs: DynUnit::Voltage })
+        );
+    }
+
+    #[test]
+    fn test_flux_from_inductance() {
+        let l = u!(2 H);
+        let flux = l * u!(3 A);
+        assert_eq!(flux, u!(6 Wb));
+        assert_eq!(flux / u!(3 A), l);
+        assert_eq!(flux / l, u!(3 A));
+    }
+
+    #[test]
+    fn test_ohms_law_conductance() {
+        let i = v!(10.0) * g!(0.5);
+        assert_eq!(i, u!(5 A));
+        assert_eq!(i / g!(0.5), v!(10));
+        assert_eq!(i / v!(10), g!(0.5));
+    }
+
+    #[test]
+    fn test_abs_diff_rel_error() {
+        let measured = u!(1.05 V);
+        let expected = u!(1.0 V);
+        assert!((measured.rel_error(expected).to_f64() - 0.05).abs() < 1e-12);
+        assert!((measured.abs_diff(expected).to_f64() - 0.05).abs() < 1e-12);
+        assert_eq!(measured.abs_diff(expected).round_dp(3).to_string(), "50mV");
+        assert_eq!(expected.abs_diff(measured), measured.abs_diff(expected));
+    }
+
+    #[test]
+    fn test_ratio_as_percent() {
+        let vin = u!(2.0 V);
+        let vout = u!(500 mV);
+        let gain = vout.ratio(vin);
+        assert_eq!(gain, Ratio::new(vout / vin));
+        assert_eq!(gain.to_string(), "250m");
+        assert_eq!(format!("{}%", gain.as_percent()), "25%");
+    }
+
+    #[test]
+    fn test_nearest_e_series() {
+        use crate::unit::ESeries;
+
+        assert_eq!(u!(3.3 kΩ).nearest_e_series(ESeries::E24), u!(3.3 kΩ));
+        assert_eq!(u!(3.4 kΩ).nearest_e_series(ESeries::E24), u!(3.3 kΩ));
+        assert_eq!(u!(3.4 kΩ).nearest_e_series(ESeries::E96), u!(3.4 kΩ));
+        assert_eq!(u!(5 kΩ).nearest_e_series(ESeries::E12), u!(4.7 kΩ));
+        assert_eq!(u!(9.9 Ω).nearest_e_series(ESeries::E12), u!(10 Ω));
+        assert_eq!(u!(0.34 Ω).nearest_e_series(ESeries::E24), u!(0.33 Ω));
+        assert_eq!(u!(1.05 uF).nearest_e_series(ESeries::E12), u!(1 uF));
+        assert_eq!(u!(0 Ω).nearest_e_series(ESeries::E12), u!(0 Ω));
+    }
+
+    #[test]
+    fn test_series_parallel_components() {
+        assert_eq!(u!(100 Ω).parallel(u!(100 Ω)), u!(50 Ω));
+        assert_eq!(u!(100 Ω).series(u!(100 Ω)), u!(200 Ω));
+        assert_eq!(u!(100 Ω).parallel(u!(0 Ω)), u!(0 Ω));
+        assert_eq!(Resistance::parallel_all(&[u!(30 Ω), u!(60 Ω), u!(20 Ω)]), u!(10 Ω));
+        assert!(Resistance::parallel_all(&[]).to_f64().is_infinite());
+
+        let c = u!(1 uF).series(u!(1 uF));
+        assert!((c.to_f64() - 0.5e-6).abs() < 1e-18);
+        assert_eq!(u!(1 uF).parallel(u!(1 uF)), u!(2 uF));
+    }
+
+    #[test]
+    fn test_impedance_at() {
+        use crate::Complex;
+
+        // ω = 1 rad/s
+        let f = Frequency::new(1. / std::f64::consts::TAU);
+        assert!((f.angular().to_f64() - 1.).abs() < 1e-12);
+
+        let z = Capacitance::new(1.0).impedance_at(f);
+        assert_eq!(z.re, num!(0));
+        assert!((z.im.to_f64() + 1.).abs() < 1e-12);
+        assert!((z.norm_sqr().to_f64() - 1.).abs() < 1e-12);
+
+        let z = u!(1 uF).impedance_at(f);
+        assert!((z.im.to_f64() + 1e6).abs() < 1e-6);
+
+        let z = u!(1 mH).impedance_at(u!(1 kHz));
+        assert!((z.im.to_f64() - std::f64::consts::TAU).abs() < 1e-12);
+        assert_eq!(u!(50 Ω).impedance_at(u!(1 MHz)), Complex::new(50, 0));
+    }
+
+    #[test]
+    fn test_unit_complex() {
+        use crate::{Complex, UnitComplex};
+
+        let v: UnitComplex<VoltageUnit> = UnitComplex::new(Complex::new(10, 5));
+        let i: UnitComplex<CurrentUnit> = UnitComplex::new(Complex::new(2, 1));
+        let z: UnitComplex<ResistanceUnit> = v / i;
+        assert_eq!(z.value(), Complex::new(5, 0));
+        assert_eq!(z.to_string(), "5Ω");
+        assert_eq!((z * i).value(), v.value());
+
+        assert_eq!(v + v, UnitComplex::new(Complex::new(20, 10)));
+        assert_eq!((v - v).value(), Complex::new(0, 0));
+        assert_eq!(v.re(), u!(10 V));
+        assert_eq!(v.im(), u!(5 V));
+
+        let parsed: UnitComplex<VoltageUnit> = "10+5jV".parse().unwrap();
+        assert_eq!(parsed, v);
+        assert_eq!(v.to_string(), "10+5jV");
+        assert_eq!(format!("{:>8}|", v), "  10+5jV|");
+        assert_eq!(format!("{:-<10.1}|", v), "10.0+5.0jV|");
+        assert_eq!(format!("{:^12.1}|", v), " 10.0+5.0jV |");
+        assert!("10+5jA".parse::<UnitComplex<VoltageUnit>>().is_err());
+
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!(json, "\"10+5jV\"");
+        assert_eq!(serde_json::from_str::<UnitComplex<VoltageUnit>>(&json).unwrap(), v);
+    }
+
+    #[test]
+    fn test_mass_symbol_prefixes() {
+        let light = Mass::new(0.5);
+        assert_eq!(light.to_string(), "500g");
+        assert_eq!(mass!(500), light);
+        assert_eq!(mass!(500 m), u!(500 mg));
+        assert_eq!(mass!(500 m).to_string(), "500mg");
+        assert_eq!(mass!(1 K).to_string(), "1Kg");
+        assert_eq!(u!(500 g), light);
+        assert_eq!("500g".parse::<Mass>().unwrap(), light);
+        assert_eq!("0.5kg".parse::<Mass>().unwrap(), light);
+        assert_eq!(u!(2.5 mg).to_string(), "2.5mg");
+
+        let heavy = Mass::new(1500.0);
+        assert_eq!(heavy.to_string(), "1.5Mg");
+        assert_eq!("1500kg".parse::<Mass>().unwrap(), heavy);
+        assert_eq!(u!(1.5 Mg), heavy);
+        assert_eq!(heavy.in_suffix(Suffix::Kilo), 1500.0);
+
+        assert!("1mkg".parse::<Mass>().is_err());
+        assert_eq!(serde_json::to_string(&light).unwrap(), "\"500g\"");
+    }
+
+    #[test]
+    fn test_unit_complex_from_parts() {
+        use crate::{Complex, UnitComplex};
+
+        let v = UnitComplex::from_parts(u!(3 V), u!(-4 mV));
+        assert_eq!(v.value(), Complex::new(num!(3), num!(-4 m)));
+        assert_eq!(v.re(), u!(3 V));
+        assert_eq!(v.im(), u!(-4 mV));
+        assert_eq!(UnitComplex::from_parts(v.re(), v.im()), v);
+    }
+
+    #[test]
+    fn test_classify_standard() {
+        use crate::unit::ESeries;
+
+        assert_eq!(u!(4.7 kΩ).classify_standard(), (u!(4.7 kΩ), ESeries::E6));
+        assert_eq!(u!(4.64 kΩ).classify_standard(), (u!(4.64 kΩ), ESeries::E96));
+        assert_eq!(u!(5.1 kΩ).classify_standard(), (u!(5.1 kΩ), ESeries::E24));
+        assert_eq!(u!(1.2 kΩ).classify_standard(), (u!(1.2 kΩ), ESeries::E12));
+        assert_eq!(u!(4.7 kΩ).nearest_e_series(ESeries::E6), u!(4.7 kΩ));
+        // More than 1% from any E96 value: saturates to the nearest one
+        assert_eq!(u!(9.64 kΩ).classify_standard(), (u!(9.53 kΩ), ESeries::E96));
+        assert_eq!(ESeries::E6.tolerance(), 0.2);
+    }
 }
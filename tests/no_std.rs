@@ -0,0 +1,15 @@
+use std::process::Command;
+
+/// Builds the library with `--no-default-features`, i.e. as `#![no_std]` + `alloc`.
+#[test]
+fn builds_without_std() {
+    let output = Command::new(env!("CARGO"))
+        .args(["check", "--lib", "--no-default-features", "--features", "num-traits,serde-float"])
+        .arg("--target-dir")
+        .arg(concat!(env!("CARGO_TARGET_TMPDIR"), "/no_std"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("failed to run cargo");
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
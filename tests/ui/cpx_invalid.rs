@@ -0,0 +1,6 @@
+use runit::cpx;
+
+fn main() {
+    let _ = cpx!(1.5 + 2.5xj);
+    let _ = cpx!(1.5 +);
+}
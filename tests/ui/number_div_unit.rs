@@ -0,0 +1,5 @@
+use runit::{num, v};
+
+fn main() {
+    let _ = num!(1.0) / v!(2.0);
+}